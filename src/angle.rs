@@ -15,10 +15,12 @@
 
 //! Angle units for type-safe, self-documenting code.
 
+use std::error;
 use std::f64;
 use std::fmt;
 use std::iter;
 use std::ops::*;
+use std::str::FromStr;
 
 use num_traits::{cast, Bounded};
 #[cfg(feature = "rand")]
@@ -34,20 +36,44 @@ use num::{BaseFloat, BaseNum};
 
 /// An angle, in radians.
 ///
-/// This type is marked as `#[repr(C)]`.
+/// This type is marked as `#[repr(C)]`. `Eq` and `Hash` are only implemented
+/// for integer storage, so float angles are neither; use `hash_key` as the key
+/// for them instead.
 #[repr(C)]
-#[derive(Copy, Clone, PartialEq, PartialOrd)]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Rad<S>(pub S);
 
 /// An angle, in degrees.
 ///
-/// This type is marked as `#[repr(C)]`.
+/// This type is marked as `#[repr(C)]`. `Eq` and `Hash` are only implemented
+/// for integer storage, so float angles are neither; use `hash_key` as the key
+/// for them instead.
 #[repr(C)]
-#[derive(Copy, Clone, PartialEq, PartialOrd)]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Deg<S>(pub S);
 
+/// An angle, in gradians.
+///
+/// This type is marked as `#[repr(C)]`. `Eq` and `Hash` are only implemented
+/// for integer storage, so float angles are neither; use `hash_key` as the key
+/// for them instead.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Grad<S>(pub S);
+
+/// An angle, in fractions of a full turn.
+///
+/// This type is marked as `#[repr(C)]`. `Eq` and `Hash` are only implemented
+/// for integer storage, so float angles are neither; use `hash_key` as the key
+/// for them instead.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Turn<S>(pub S);
+
 impl<S> From<Rad<S>> for Deg<S>
 where
     S: BaseFloat,
@@ -68,8 +94,161 @@ where
     }
 }
 
+impl<S> From<Rad<S>> for Grad<S>
+where
+    S: BaseFloat,
+{
+    #[inline]
+    fn from(rad: Rad<S>) -> Grad<S> {
+        Grad(rad.0 * cast(200.0 / f64::consts::PI).unwrap())
+    }
+}
+
+impl<S> From<Grad<S>> for Rad<S>
+where
+    S: BaseFloat,
+{
+    #[inline]
+    fn from(grad: Grad<S>) -> Rad<S> {
+        Rad(grad.0 * cast(f64::consts::PI / 200.0).unwrap())
+    }
+}
+
+impl<S> From<Deg<S>> for Grad<S>
+where
+    S: BaseFloat,
+{
+    #[inline]
+    fn from(deg: Deg<S>) -> Grad<S> {
+        Grad(deg.0 * cast(400.0 / 360.0).unwrap())
+    }
+}
+
+impl<S> From<Grad<S>> for Deg<S>
+where
+    S: BaseFloat,
+{
+    #[inline]
+    fn from(grad: Grad<S>) -> Deg<S> {
+        Deg(grad.0 * cast(360.0 / 400.0).unwrap())
+    }
+}
+
+impl<S> From<Rad<S>> for Turn<S>
+where
+    S: BaseFloat,
+{
+    #[inline]
+    fn from(rad: Rad<S>) -> Turn<S> {
+        Turn(rad.0 * cast(0.5 / f64::consts::PI).unwrap())
+    }
+}
+
+impl<S> From<Turn<S>> for Rad<S>
+where
+    S: BaseFloat,
+{
+    #[inline]
+    fn from(turn: Turn<S>) -> Rad<S> {
+        Rad(turn.0 * cast(f64::consts::PI * 2.0).unwrap())
+    }
+}
+
+impl<S> From<Deg<S>> for Turn<S>
+where
+    S: BaseFloat,
+{
+    #[inline]
+    fn from(deg: Deg<S>) -> Turn<S> {
+        Turn(deg.0 * cast(1.0 / 360.0).unwrap())
+    }
+}
+
+impl<S> From<Turn<S>> for Deg<S>
+where
+    S: BaseFloat,
+{
+    #[inline]
+    fn from(turn: Turn<S>) -> Deg<S> {
+        Deg(turn.0 * cast(360.0).unwrap())
+    }
+}
+
+impl<S> From<Grad<S>> for Turn<S>
+where
+    S: BaseFloat,
+{
+    #[inline]
+    fn from(grad: Grad<S>) -> Turn<S> {
+        Turn(grad.0 * cast(1.0 / 400.0).unwrap())
+    }
+}
+
+impl<S> From<Turn<S>> for Grad<S>
+where
+    S: BaseFloat,
+{
+    #[inline]
+    fn from(turn: Turn<S>) -> Grad<S> {
+        Grad(turn.0 * cast(400.0).unwrap())
+    }
+}
+
+impl<S: BaseFloat> Deg<S> {
+    /// Construct an angle from degrees, arcminutes and arcseconds.
+    ///
+    /// The sign of the angle is taken from the degrees component, so negative
+    /// angles of less than a degree can be written using `-0.0` degrees.
+    ///
+    /// ```rust
+    /// use cgmath::Deg;
+    ///
+    /// assert_eq!(Deg::from_dms(-0.0, 30.0, 0.0), Deg(-0.5));
+    /// ```
+    #[inline]
+    pub fn from_dms(degrees: S, minutes: S, seconds: S) -> Deg<S> {
+        let sixty: S = cast(60).unwrap();
+        let magnitude = degrees.abs() + minutes / sixty + seconds / (sixty * sixty);
+        if degrees.is_sign_negative() {
+            Deg(-magnitude)
+        } else {
+            Deg(magnitude)
+        }
+    }
+
+    /// Split the angle into degrees, arcminutes and arcseconds.
+    ///
+    /// The sign of the angle is carried only by the degrees component, which
+    /// will be `-0.0` for negative angles of less than a degree.
+    #[inline]
+    pub fn to_dms(self) -> (S, S, S) {
+        let sixty: S = cast(60).unwrap();
+        let magnitude = self.0.abs();
+        let degrees = magnitude.trunc();
+        let minutes = ((magnitude - degrees) * sixty).trunc();
+        let seconds = ((magnitude - degrees) * sixty - minutes) * sixty;
+        if self.0.is_sign_negative() {
+            (-degrees, minutes, seconds)
+        } else {
+            (degrees, minutes, seconds)
+        }
+    }
+}
+
+/// An error returned when parsing an angle from a string fails.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ParseAngleError;
+
+impl fmt::Display for ParseAngleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid angle literal")
+    }
+}
+
+impl error::Error for ParseAngleError {}
+
 macro_rules! impl_angle {
-    ($Angle:ident, $fmt:expr, $full_turn:expr, $hi:expr) => {
+    ($Angle:ident, $unit:expr, [$($suffix:expr),+], $full_turn:expr) => {
         impl<S: BaseFloat> Zero for $Angle<S> {
             #[inline]
             fn zero() -> $Angle<S> {
@@ -211,23 +390,76 @@ macro_rules! impl_angle {
             }
         }
 
+        impl<S: BaseFloat> $Angle<S> {
+            /// A key identifying the direction of the angle, for use where
+            /// `Eq` and `Hash` are needed with float storage.
+            ///
+            /// This is the IEEE 754 bit pattern of the angle, as an `f64`, once
+            /// normalized to `[0, full_turn)`, with `-0.0` folded to `0.0`.
+            /// Angles that normalize to the same value, such as ones a whole
+            /// number of turns apart, have the same key. The angle itself is
+            /// still neither `Eq` nor `Hash` when `S` is a float.
+            #[inline]
+            pub fn hash_key(self) -> u64 {
+                let value = self.normalize().0.to_f64().unwrap();
+                if value == 0.0 {
+                    0.0f64.to_bits()
+                } else {
+                    value.to_bits()
+                }
+            }
+        }
+
+        /// Samples an angle uniformly from the range `[0, full_turn)`.
         #[cfg(feature = "rand")]
         impl<S> Distribution<$Angle<S>> for Standard
             where Standard: Distribution<S>,
                 S: BaseFloat + SampleUniform {
             #[inline]
             fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $Angle<S> {
-                $Angle(rng.gen_range(cast::<_, S>(-$hi).unwrap() .. cast::<_, S>($hi).unwrap()))
+                $Angle(rng.gen_range(S::zero() .. $Angle::<S>::full_turn().0))
             }
         }
 
+        #[cfg(feature = "rand")]
+        impl<S> $Angle<S>
+            where Standard: Distribution<S>,
+                S: BaseFloat + SampleUniform {
+            /// Samples an angle uniformly from the range
+            /// `(-turn_div_2, turn_div_2]`.
+            #[inline]
+            pub fn rand_signed<R: Rng + ?Sized>(rng: &mut R) -> $Angle<S> {
+                $Angle::turn_div_2() - rng.gen::<$Angle<S>>()
+            }
+        }
+
+        /// Formatting options such as precision are applied to the inner value.
         impl<S: fmt::Debug> fmt::Debug for $Angle<S> {
             fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-                write!(f, $fmt, self.0)
+                fmt::Debug::fmt(&self.0, f)?;
+                f.write_str($unit)
+            }
+        }
+
+        impl<S: BaseFloat + FromStr> FromStr for $Angle<S> {
+            type Err = ParseAngleError;
+
+            /// Parses a plain number, or a number followed by a unit suffix
+            /// such as the one produced by the `Debug` impl.
+            fn from_str(s: &str) -> Result<$Angle<S>, ParseAngleError> {
+                let s = s.trim();
+                let value = [$($suffix),+].iter()
+                    .filter_map(|suffix| s.strip_suffix(suffix))
+                    .next()
+                    .unwrap_or(s)
+                    .trim_end();
+                value.parse().map($Angle).map_err(|_| ParseAngleError)
             }
         }
     }
 }
 
-impl_angle!(Rad, "{:?} rad", f64::consts::PI * 2.0, f64::consts::PI);
-impl_angle!(Deg, "{:?}°", 360, 180);
+impl_angle!(Rad, " rad", ["rad"], f64::consts::PI * 2.0);
+impl_angle!(Deg, "°", ["°", "deg"], 360);
+impl_angle!(Grad, " grad", ["grad"], 400);
+impl_angle!(Turn, " turn", ["turns", "turn"], 1);
@@ -0,0 +1,155 @@
+// Copyright 2026 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Support for the `assert_approx_eq!` macro.
+
+use std::slice;
+
+use angle::{Deg, Grad, Rad, Turn};
+use matrix::{Matrix2, Matrix3, Matrix4};
+use num::BaseNum;
+use point::{Point1, Point2, Point3};
+use quaternion::Quaternion;
+use vector::{Vector1, Vector2, Vector3, Vector4};
+
+/// Asserts that two values are approximately equal, component by component.
+///
+/// This takes the same optional `epsilon` and `max_relative` arguments as
+/// `assert_relative_eq!`, but on failure reports the index of the first
+/// component that differs, the two components and the difference between
+/// them, before the full values.
+///
+/// ```rust
+/// #[macro_use]
+/// extern crate cgmath;
+///
+/// use cgmath::Vector3;
+///
+/// # fn main() {
+/// let a = Vector3::new(1.0e4, 2.0, 3.0);
+/// let b = Vector3::new(1.0e4 + 0.01, 2.0, 3.0);
+/// assert_approx_eq!(a, b, max_relative = 1.0e-5);
+/// assert_approx_eq!(a.y, b.y);
+/// # }
+/// ```
+///
+/// ```should_panic
+/// #[macro_use]
+/// extern crate cgmath;
+///
+/// use cgmath::Vector3;
+///
+/// # fn main() {
+/// // panics with "component 1: 2.0 != 2.5, delta -0.5"
+/// assert_approx_eq!(Vector3::new(1.0, 2.0, 3.0), Vector3::new(1.0, 2.5, 3.0));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! assert_approx_eq {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {{
+        let (given, expected) = (&$given, &$expected);
+        let components = $crate::assert::Components::components(given)
+            .iter()
+            .zip($crate::assert::Components::components(expected));
+        for (i, (a, b)) in components.enumerate() {
+            if !$crate::Relative::default()$(.$opt($val))*.eq(a, b) {
+                panic!(
+                    "assert_approx_eq!({}, {}) failed\n    component {}: {:?} != {:?}, delta {:?}\n    left  = {:?}\n    right = {:?}",
+                    stringify!($given),
+                    stringify!($expected),
+                    i,
+                    a,
+                    b,
+                    *a - *b,
+                    given,
+                    expected,
+                );
+            }
+        }
+    }};
+}
+
+/// The scalar components of a value, in storage order.
+#[doc(hidden)]
+pub trait Components {
+    type Scalar;
+
+    fn components(&self) -> &[Self::Scalar];
+}
+
+macro_rules! impl_components_as_array {
+    ($($Type:ident, $n:expr);+) => {
+        $(
+            impl<S> Components for $Type<S> {
+                type Scalar = S;
+
+                #[inline]
+                fn components(&self) -> &[S] {
+                    AsRef::<[S; $n]>::as_ref(self)
+                }
+            }
+        )+
+    };
+}
+
+impl_components_as_array!(
+    Vector1, 1; Vector2, 2; Vector3, 3; Vector4, 4;
+    Point1, 1; Point2, 2; Point3, 3;
+    Matrix2, 4; Matrix3, 9; Matrix4, 16
+);
+
+impl<S: BaseNum> Components for Quaternion<S> {
+    type Scalar = S;
+
+    #[inline]
+    fn components(&self) -> &[S] {
+        AsRef::<[S; 4]>::as_ref(self)
+    }
+}
+
+macro_rules! impl_components_as_value {
+    ($($Angle:ident),+) => {
+        $(
+            impl<S> Components for $Angle<S> {
+                type Scalar = S;
+
+                #[inline]
+                fn components(&self) -> &[S] {
+                    slice::from_ref(&self.0)
+                }
+            }
+        )+
+    };
+}
+
+impl_components_as_value!(Rad, Deg, Grad, Turn);
+
+impl Components for f32 {
+    type Scalar = f32;
+
+    #[inline]
+    fn components(&self) -> &[f32] {
+        slice::from_ref(self)
+    }
+}
+
+impl Components for f64 {
+    type Scalar = f64;
+
+    #[inline]
+    fn components(&self) -> &[f64] {
+        slice::from_ref(self)
+    }
+}
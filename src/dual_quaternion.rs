@@ -0,0 +1,281 @@
+// Copyright 2026 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::*;
+
+use num_traits::cast;
+
+use structure::*;
+
+use approx;
+use matrix::{Matrix3, Matrix4};
+use num::BaseFloat;
+use point::Point3;
+use quaternion::Quaternion;
+use rotation::Rotation;
+use transform::{Transform, Transform3};
+use vector::Vector3;
+
+/// A [dual quaternion](https://en.wikipedia.org/wiki/Dual_quaternion),
+/// representing a rigid transformation made up of a rotation followed by a
+/// translation.
+///
+/// Unlike matrices, unit dual quaternions can be blended with `nlerp`
+/// without introducing scale or shear, which makes them well suited to
+/// skinning.
+///
+/// This type is marked as `#[repr(C)]`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DualQuaternion<S> {
+    /// The real part, which holds the rotation.
+    pub real: Quaternion<S>,
+    /// The dual part, which holds the translation.
+    pub dual: Quaternion<S>,
+}
+
+impl<S> DualQuaternion<S> {
+    /// Construct a new dual quaternion from its real and dual parts.
+    #[inline]
+    pub const fn new(real: Quaternion<S>, dual: Quaternion<S>) -> DualQuaternion<S> {
+        DualQuaternion { real, dual }
+    }
+}
+
+impl<S: BaseFloat> DualQuaternion<S> {
+    /// Create a dual quaternion that applies `rotation` followed by
+    /// `translation`. The rotation should be normalized first.
+    pub fn from_rotation_translation(
+        rotation: Quaternion<S>,
+        translation: Vector3<S>,
+    ) -> DualQuaternion<S> {
+        let half: S = cast(0.5f64).unwrap();
+        let dual = Quaternion::from_sv(S::zero(), translation * half) * rotation;
+        DualQuaternion::new(rotation, dual)
+    }
+
+    /// Create a dual quaternion from a rotation. The rotation should be
+    /// normalized first.
+    #[inline]
+    pub fn from_rotation(rotation: Quaternion<S>) -> DualQuaternion<S> {
+        DualQuaternion::new(rotation, Quaternion::zero())
+    }
+
+    /// Create a dual quaternion from a translation.
+    #[inline]
+    pub fn from_translation(translation: Vector3<S>) -> DualQuaternion<S> {
+        DualQuaternion::from_rotation_translation(Quaternion::one(), translation)
+    }
+
+    /// The rotation applied by the dual quaternion.
+    #[inline]
+    pub fn rotation(self) -> Quaternion<S> {
+        self.real
+    }
+
+    /// The translation applied by the dual quaternion, after the rotation.
+    /// The dual quaternion should be normalized first.
+    #[inline]
+    pub fn translation(self) -> Vector3<S> {
+        let two: S = cast(2).unwrap();
+        (self.dual * self.real.conjugate()).v * two
+    }
+
+    /// The quaternion conjugate of both parts. For a unit dual quaternion,
+    /// this is the inverse transformation.
+    #[inline]
+    pub fn conjugate(self) -> DualQuaternion<S> {
+        DualQuaternion::new(self.real.conjugate(), self.dual.conjugate())
+    }
+
+    /// Returns a unit dual quaternion representing the same transformation.
+    ///
+    /// Both parts are divided by the magnitude of the real part, and the
+    /// dual part is made orthogonal to the real part. Normalizing a dual
+    /// quaternion with a zero real part results in `NaN` components.
+    pub fn normalize(self) -> DualQuaternion<S> {
+        let real = self.real.normalize();
+        let dual = self.dual / self.real.magnitude();
+        DualQuaternion::new(real, dual - real * real.dot(dual))
+    }
+
+    /// Do a normalized linear interpolation with `other`, by `amount`. This
+    /// is also known as dual quaternion linear blending.
+    ///
+    /// This takes the shortest path, so if the real parts have a negative
+    /// dot product, the interpolation will be between `self` and `-other`.
+    pub fn nlerp(self, mut other: DualQuaternion<S>, amount: S) -> DualQuaternion<S> {
+        if self.real.dot(other.real) < S::zero() {
+            other = -other;
+        }
+
+        (self * (S::one() - amount) + other * amount).normalize()
+    }
+
+    /// Returns `true` if every component of both the real and dual parts is
+    /// finite, that is, neither infinite nor `NaN`.
+    pub fn is_finite(&self) -> bool {
+        self.real.is_finite() && self.dual.is_finite()
+    }
+}
+
+impl<S: BaseFloat> One for DualQuaternion<S> {
+    #[inline]
+    fn one() -> DualQuaternion<S> {
+        DualQuaternion::new(Quaternion::one(), Quaternion::zero())
+    }
+}
+
+impl_operator!(<S: BaseFloat> Neg for DualQuaternion<S> {
+    fn neg(quat) -> DualQuaternion<S> {
+        DualQuaternion::new(-quat.real, -quat.dual)
+    }
+});
+
+impl_operator!(<S: BaseFloat> Mul<S> for DualQuaternion<S> {
+    fn mul(lhs, rhs) -> DualQuaternion<S> {
+        DualQuaternion::new(lhs.real * rhs, lhs.dual * rhs)
+    }
+});
+
+impl_operator!(<S: BaseFloat> Add<DualQuaternion<S> > for DualQuaternion<S> {
+    fn add(lhs, rhs) -> DualQuaternion<S> {
+        DualQuaternion::new(lhs.real + rhs.real, lhs.dual + rhs.dual)
+    }
+});
+
+impl_operator!(<S: BaseFloat> Sub<DualQuaternion<S> > for DualQuaternion<S> {
+    fn sub(lhs, rhs) -> DualQuaternion<S> {
+        DualQuaternion::new(lhs.real - rhs.real, lhs.dual - rhs.dual)
+    }
+});
+
+impl_operator!(<S: BaseFloat> Mul<DualQuaternion<S> > for DualQuaternion<S> {
+    fn mul(lhs, rhs) -> DualQuaternion<S> {
+        DualQuaternion::new(
+            lhs.real * rhs.real,
+            lhs.real * rhs.dual + lhs.dual * rhs.real,
+        )
+    }
+});
+
+impl<S: BaseFloat> approx::AbsDiffEq for DualQuaternion<S> {
+    type Epsilon = S::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> S::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+        Quaternion::abs_diff_eq(&self.real, &other.real, epsilon)
+            && Quaternion::abs_diff_eq(&self.dual, &other.dual, epsilon)
+    }
+}
+
+impl<S: BaseFloat> approx::RelativeEq for DualQuaternion<S> {
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        Quaternion::relative_eq(&self.real, &other.real, epsilon, max_relative)
+            && Quaternion::relative_eq(&self.dual, &other.dual, epsilon, max_relative)
+    }
+}
+
+impl<S: BaseFloat> approx::UlpsEq for DualQuaternion<S> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        Quaternion::ulps_eq(&self.real, &other.real, epsilon, max_ulps)
+            && Quaternion::ulps_eq(&self.dual, &other.dual, epsilon, max_ulps)
+    }
+}
+
+impl<S: BaseFloat> Transform<Point3<S>> for DualQuaternion<S> {
+    fn look_at(eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> DualQuaternion<S> {
+        let rotation = Quaternion::look_at(center - eye, up);
+        let translation = rotation.rotate_vector(Point3::origin() - eye);
+        DualQuaternion::from_rotation_translation(rotation, translation)
+    }
+
+    fn look_at_rh(eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> DualQuaternion<S> {
+        let rotation = Quaternion::look_at(eye - center, up);
+        let translation = rotation.rotate_vector(Point3::origin() - eye);
+        DualQuaternion::from_rotation_translation(rotation, translation)
+    }
+
+    fn look_at_lh(eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> DualQuaternion<S> {
+        let rotation = Quaternion::look_at(center - eye, up);
+        let translation = rotation.rotate_vector(Point3::origin() - eye);
+        DualQuaternion::from_rotation_translation(rotation, translation)
+    }
+
+    #[inline]
+    fn transform_vector(&self, vec: Vector3<S>) -> Vector3<S> {
+        self.real * vec
+    }
+
+    #[inline]
+    fn transform_point(&self, point: Point3<S>) -> Point3<S> {
+        Point3::from_vec(self.real * point.to_vec() + self.translation())
+    }
+
+    #[inline]
+    fn concat(&self, other: &DualQuaternion<S>) -> DualQuaternion<S> {
+        self * other
+    }
+
+    fn inverse_transform(&self) -> Option<DualQuaternion<S>> {
+        if ulps_eq!(self.real.magnitude2(), &S::zero()) {
+            None
+        } else {
+            Some(self.normalize().conjugate())
+        }
+    }
+}
+
+impl<S: BaseFloat> Transform3 for DualQuaternion<S> {
+    type Scalar = S;
+}
+
+impl<S: BaseFloat> From<DualQuaternion<S>> for Matrix4<S> {
+    /// Convert the dual quaternion to an affine transformation matrix. The
+    /// dual quaternion should be normalized first.
+    fn from(dual_quat: DualQuaternion<S>) -> Matrix4<S> {
+        Matrix4::from_matrix3_translation(dual_quat.real.into(), dual_quat.translation())
+    }
+}
+
+impl<S: BaseFloat> From<Matrix4<S>> for DualQuaternion<S> {
+    /// Convert a rigid transformation matrix, made up of only a rotation and
+    /// a translation, to a dual quaternion.
+    fn from(m: Matrix4<S>) -> DualQuaternion<S> {
+        let rotation = Matrix3::from(m);
+        DualQuaternion::from_rotation_translation(rotation.into(), m.w.truncate())
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+impl_bytemuck_cast!(DualQuaternion);
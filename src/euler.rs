@@ -75,6 +75,25 @@ use quaternion::Quaternion;
 /// });
 /// ```
 ///
+/// This is equivalent to composing the single axis rotations in the same order:
+///
+/// ```
+/// # #[macro_use] extern crate approx;
+/// # extern crate cgmath;
+/// use cgmath::{Deg, Euler, Quaternion, Rotation3};
+///
+/// # fn main() {
+/// let (x, y, z) = (Deg(90.0), Deg(45.0), Deg(15.0));
+/// let rotation = Quaternion::from_angle_x(x)
+///     * Quaternion::from_angle_y(y)
+///     * Quaternion::from_angle_z(z);
+/// assert_ulps_eq!(rotation, Quaternion::from(Euler { x, y, z }));
+/// # }
+/// ```
+///
+/// `Quaternion::from_euler_xyz` is the same conversion, and
+/// `Quaternion::from_euler_zyx` applies the angles in the ZYX order instead.
+///
 /// [Euler angles]: https://en.wikipedia.org/wiki/Euler_angles
 /// [gimbal lock]: https://en.wikipedia.org/wiki/Gimbal_lock#Gimbal_lock_in_applied_mathematics
 /// [convert]: #defining-rotations-using-euler-angles
@@ -103,6 +122,11 @@ impl<A> Euler<A> {
     }
 }
 
+/// Extracts the angles in the XYZ sequence used by `Euler`.
+///
+/// When the rotation around the _y_ axis is close to a quarter turn, the _x_
+/// and _z_ rotations are around the same axis (gimbal lock). In this case the
+/// _x_ angle is set to zero and the whole rotation is folded into _z_.
 impl<S: BaseFloat> From<Quaternion<S>> for Euler<Rad<S>> {
     fn from(src: Quaternion<S>) -> Euler<Rad<S>> {
         let sig: S = cast(0.499).unwrap();
@@ -49,6 +49,41 @@
 //! ```rust
 //! use cgmath::prelude::*;
 //! ```
+//!
+//! # Approximate equality
+//!
+//! Vectors, points, matrices, quaternions and angles implement the traits of
+//! the [`approx`](https://docs.rs/approx) crate, which is re-exported. These
+//! compare component-wise, with a configurable tolerance:
+//!
+//! - `abs_diff_eq!(a, b, epsilon = e)` compares the absolute difference of
+//!   each component against `e`.
+//! - `relative_eq!(a, b, max_relative = r)` scales the tolerance by the size
+//!   of each component, which is better suited to large values such as those
+//!   in projection matrices.
+//! - `ulps_eq!(a, b)` compares by units in the last place.
+//!
+//! The `assert_abs_diff_eq!`, `assert_relative_eq!` and `assert_ulps_eq!`
+//! variants panic with both values when the comparison fails. For large values
+//! such as matrices, `assert_approx_eq!` takes the same arguments as
+//! `assert_relative_eq!` and also reports which component differs, and by how
+//! much.
+//!
+//! ```rust
+//! #[macro_use]
+//! extern crate approx;
+//! extern crate cgmath;
+//!
+//! use cgmath::Vector3;
+//!
+//! # fn main() {
+//! let a = Vector3::new(1.0e4, 2.0, 3.0);
+//! let b = Vector3::new(1.0e4 + 0.01, 2.0, 3.0);
+//! assert!(!abs_diff_eq!(a, b));
+//! assert!(abs_diff_eq!(a, b, epsilon = 0.1));
+//! assert_relative_eq!(a, b, max_relative = 1.0e-5);
+//! # }
+//! ```
 
 #![cfg_attr(feature = "simd", feature(specialization))]
 
@@ -78,11 +113,15 @@ pub use approx::*;
 pub use num::*;
 pub use structure::*;
 
+pub use dual_quaternion::DualQuaternion;
 pub use matrix::{Matrix2, Matrix3, Matrix4};
 pub use quaternion::Quaternion;
-pub use vector::{dot, vec1, vec2, vec3, vec4, Vector1, Vector2, Vector3, Vector4};
+pub use vector::{
+    all, any, centroid, dot, vec1, vec2, vec3, vec4, ParseVectorError, Vector1, Vector2, Vector3,
+    Vector4,
+};
 
-pub use angle::{Deg, Rad};
+pub use angle::{Deg, Grad, ParseAngleError, Rad, Turn};
 pub use euler::Euler;
 pub use point::{point1, point2, point3, Point1, Point2, Point3};
 pub use rotation::*;
@@ -97,9 +136,13 @@ pub mod prelude;
 
 mod macros;
 
+#[doc(hidden)]
+pub mod assert;
+
 mod num;
 mod structure;
 
+mod dual_quaternion;
 mod matrix;
 mod quaternion;
 
@@ -96,6 +96,15 @@ impl<S> Matrix2<S> {
     pub const fn from_cols(c0: Vector2<S>, c1: Vector2<S>) -> Matrix2<S> {
         Matrix2 { x: c0, y: c1 }
     }
+
+    /// Create a new matrix, providing rows. This is the transpose of the matrix
+    /// created by `from_cols` with the same arguments.
+    #[inline]
+    pub fn from_rows(r0: Vector2<S>, r1: Vector2<S>) -> Matrix2<S> {
+        let Vector2 { x: r0c0, y: r0c1 } = r0;
+        let Vector2 { x: r1c0, y: r1c1 } = r1;
+        Matrix2::new(r0c0, r1c0, r0c1, r1c1)
+    }
 }
 
 impl<S: BaseFloat> Matrix2<S> {
@@ -156,6 +165,45 @@ impl<S> Matrix3<S> {
             z: c2,
         }
     }
+
+    /// Create a new matrix, providing rows. This is the transpose of the matrix
+    /// created by `from_cols` with the same arguments.
+    ///
+    /// For an orthonormal basis, `from_cols` creates a matrix converting from
+    /// coordinates in that basis, and `from_rows` one converting to them:
+    ///
+    /// ```
+    /// use cgmath::{Matrix3, Vector3};
+    ///
+    /// let x = Vector3::new(0.0, 1.0, 0.0);
+    /// let y = Vector3::new(-1.0, 0.0, 0.0);
+    /// let z = Vector3::new(0.0, 0.0, 1.0);
+    /// let from_basis = Matrix3::from_cols(x, y, z);
+    /// let to_basis = Matrix3::from_rows(x, y, z);
+    ///
+    /// let v = Vector3::new(1.0, 2.0, 3.0);
+    /// assert_eq!(from_basis * v, x * 1.0 + y * 2.0 + z * 3.0);
+    /// assert_eq!(to_basis * (from_basis * v), v);
+    /// ```
+    #[inline]
+    pub fn from_rows(r0: Vector3<S>, r1: Vector3<S>, r2: Vector3<S>) -> Matrix3<S> {
+        let Vector3 {
+            x: r0c0,
+            y: r0c1,
+            z: r0c2,
+        } = r0;
+        let Vector3 {
+            x: r1c0,
+            y: r1c1,
+            z: r1c2,
+        } = r1;
+        let Vector3 {
+            x: r2c0,
+            y: r2c1,
+            z: r2c2,
+        } = r2;
+        Matrix3::new(r0c0, r1c0, r2c0, r0c1, r1c1, r2c1, r0c2, r1c2, r2c2)
+    }
 }
 
 impl<S: BaseFloat> Matrix3<S> {
@@ -196,18 +244,20 @@ impl<S: BaseFloat> Matrix3<S> {
 
     /// Create a rotation matrix that will cause a vector to point at
     /// `dir`, using `up` for orientation.
+    ///
+    /// Degenerate inputs are handled as in `Matrix4::look_to_rh`.
     pub fn look_to_lh(dir: Vector3<S>, up: Vector3<S>) -> Matrix3<S> {
-        let dir = dir.normalize();
-        let side = up.cross(dir).normalize();
-        let up = dir.cross(side).normalize();
-
-        Matrix3::from_cols(side, up, dir).transpose()
+        Matrix3::look_to_rh(-dir, up)
     }
 
     /// Create a rotation matrix that will cause a vector to point at
     /// `dir`, using `up` for orientation.
+    ///
+    /// Degenerate inputs are handled as in `Matrix4::look_to_rh`.
     pub fn look_to_rh(dir: Vector3<S>, up: Vector3<S>) -> Matrix3<S> {
-        Matrix3::look_to_lh(-dir, up)
+        let (f, s, u) = look_basis(dir, up);
+
+        Matrix3::from_cols(s, u, -f).transpose()
     }
 
     /// Create a rotation matrix from a rotation around the `x` axis (pitch).
@@ -249,9 +299,12 @@ impl<S: BaseFloat> Matrix3<S> {
         )
     }
 
-    /// Create a rotation matrix from an angle around an arbitrary axis.
+    /// Create a rotation matrix from an angle around an arbitrary axis, using
+    /// [Rodrigues' rotation formula](https://en.wikipedia.org/wiki/Rodrigues%27_rotation_formula).
     ///
     /// The specified axis **must be normalized**, or it represents an invalid rotation.
+    /// It is not normalized internally, so that callers with a known unit axis
+    /// do not pay for it.
     pub fn from_axis_angle<A: Into<Rad<S>>>(axis: Vector3<S>, angle: A) -> Matrix3<S> {
         let (s, c) = Rad::sin_cos(angle.into());
         let _1subc = S::one() - c;
@@ -272,6 +325,176 @@ impl<S: BaseFloat> Matrix3<S> {
         )
     }
 
+    /// Create a rotation matrix from a rotation vector, whose direction is
+    /// the axis of rotation and whose magnitude is the angle of rotation in
+    /// radians. This is the exponential map of `Matrix3::from_cross(w)`.
+    ///
+    /// The zero vector results in the identity matrix.
+    pub fn from_rotation_vector(w: Vector3<S>) -> Matrix3<S> {
+        let angle = w.magnitude();
+        if ulps_eq!(angle, &S::zero()) {
+            // To first order, the exponential map is `I + K`
+            Matrix3::identity() + Matrix3::from_cross(w)
+        } else {
+            Matrix3::from_axis_angle(w / angle, Rad(angle))
+        }
+    }
+
+    /// Convert a rotation matrix to a rotation vector, with a magnitude in
+    /// the range `[0, pi]`. This is the logarithm map, and is the inverse
+    /// of `Matrix3::from_rotation_vector`.
+    ///
+    /// For a rotation of exactly `pi` radians, both `w` and `-w` represent
+    /// the same rotation, and either may be returned.
+    pub fn to_rotation_vector(&self) -> Vector3<S> {
+        let two: S = cast(2).unwrap();
+
+        // Twice the sine of the angle, along the axis of rotation
+        let skew = Vector3::new(
+            self[1][2] - self[2][1],
+            self[2][0] - self[0][2],
+            self[0][1] - self[1][0],
+        );
+        let cos = (self.trace() - S::one()) / two;
+        let sin = skew.magnitude() / two;
+        let angle = Rad::atan2(sin, cos);
+
+        if cos >= S::zero() {
+            // Close to zero, the angle is approximately equal to its sine
+            if sin.is_zero() {
+                skew / two
+            } else {
+                skew * (angle.0 / (two * sin))
+            }
+        } else {
+            // Close to pi, the skew-symmetric part vanishes, so the axis is
+            // recovered from the symmetric part instead, which is
+            // `(1 - cos) * axis * axis^T` after removing `cos * I`.
+            let diagonal = self.diagonal() - Vector3::from_value(cos);
+            let i = if diagonal.x >= diagonal.y && diagonal.x >= diagonal.z {
+                0
+            } else if diagonal.y >= diagonal.z {
+                1
+            } else {
+                2
+            };
+            let mut column = (self[i] + self.row(i)) / two;
+            column[i] -= cos;
+            let mut axis = column.normalize();
+            if axis.dot(skew) < S::zero() {
+                axis = -axis;
+            }
+            axis * angle.0
+        }
+    }
+
+    /// Create the skew-symmetric matrix `K` of a vector `w`, such that
+    /// `K * v` is equal to `w.cross(v)` for any `v`.
+    #[rustfmt::skip]
+    pub fn from_cross(w: Vector3<S>) -> Matrix3<S> {
+        Matrix3::new(
+            S::zero(), w.z, -w.y,
+            -w.z, S::zero(), w.x,
+            w.y, -w.x, S::zero(),
+        )
+    }
+
+    /// Create the outer product of two vectors, `a * b^T`, such that
+    /// `from_outer(a, b) * v` is equal to `a * b.dot(v)` for any `v`.
+    #[inline]
+    pub fn from_outer(a: Vector3<S>, b: Vector3<S>) -> Matrix3<S> {
+        a.outer(b)
+    }
+
+    /// Make the columns of the matrix orthonormal using the modified
+    /// Gram-Schmidt process, for correcting the drift of a rotation matrix
+    /// that has been accumulated over many operations.
+    ///
+    /// The first column keeps its direction, and the sign of the determinant
+    /// is preserved, so a rotation matrix remains a rotation matrix rather
+    /// than picking up a reflection.
+    pub fn orthonormalize(&self) -> Matrix3<S> {
+        let x = self.x.normalize();
+        let y = self.y - x * x.dot(self.y);
+        let y = y.normalize();
+        let z = self.z - x * x.dot(self.z);
+        let z = z - y * y.dot(z);
+        Matrix3::from_cols(x, y, z.normalize())
+    }
+
+    /// Make the columns of the matrix orthonormal in-place. See
+    /// `orthonormalize`.
+    pub fn orthonormalize_self(&mut self) {
+        *self = self.orthonormalize();
+    }
+
+    /// Compute the eigenvalues and eigenvectors of a symmetric matrix, such
+    /// as a covariance matrix, using cyclic Jacobi rotations.
+    ///
+    /// Returns `(e, v)`, where `e` holds the eigenvalues sorted in descending
+    /// order, and `v` is a rotation matrix whose columns are the
+    /// corresponding normalized eigenvectors, such that `self` is equal to
+    /// `v * Matrix3::from_diagonal(e) * v.transpose()`.
+    ///
+    /// In debug builds, this panics if the matrix is not symmetric.
+    pub fn eigen_symmetric(&self) -> (Vector3<S>, Matrix3<S>) {
+        debug_assert!(
+            self.is_symmetric(),
+            "The matrix is not symmetric, found: {:?}",
+            self
+        );
+
+        let two: S = cast(2).unwrap();
+        let mut a = *self;
+        let mut v = Matrix3::identity();
+        for _ in 0..32 {
+            let off_diagonal = a[1][0].abs() + a[2][0].abs() + a[2][1].abs();
+            let scale = off_diagonal + a[0][0].abs() + a[1][1].abs() + a[2][2].abs();
+            if off_diagonal <= scale * S::epsilon() {
+                break;
+            }
+
+            for &(p, q) in [(0, 1), (0, 2), (1, 2)].iter() {
+                if a[q][p].is_zero() {
+                    continue;
+                }
+
+                // Find the rotation in the `pq` plane that zeroes `a[q][p]`
+                let theta = (a[q][q] - a[p][p]) / (two * a[q][p]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + S::one()).sqrt());
+                let c = S::one() / (t * t + S::one()).sqrt();
+                let s = t * c;
+
+                let mut rotation = Matrix3::identity();
+                rotation[p][p] = c;
+                rotation[q][q] = c;
+                rotation[q][p] = s;
+                rotation[p][q] = -s;
+
+                a = rotation.transpose() * a * rotation;
+                v *= rotation;
+            }
+        }
+
+        let mut order = [0, 1, 2];
+        order.sort_by(|&i, &j| {
+            a[j][j]
+                .partial_cmp(&a[i][i])
+                .unwrap_or(::std::cmp::Ordering::Equal)
+        });
+        let values = Vector3::new(
+            a[order[0]][order[0]],
+            a[order[1]][order[1]],
+            a[order[2]][order[2]],
+        );
+        let mut vectors = Matrix3::from_cols(v[order[0]], v[order[1]], v[order[2]]);
+        if vectors.determinant() < S::zero() {
+            vectors.z = -vectors.z;
+        }
+
+        (values, vectors)
+    }
+
     /// Are all entries in the matrix finite.
     pub fn is_finite(&self) -> bool {
         self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
@@ -311,6 +534,28 @@ impl<S> Matrix4<S> {
             w: c3,
         }
     }
+
+    /// Create a new matrix, providing rows. This is the transpose of the matrix
+    /// created by `from_cols` with the same arguments.
+    #[inline]
+    #[rustfmt::skip]
+    pub fn from_rows(
+        r0: Vector4<S>,
+        r1: Vector4<S>,
+        r2: Vector4<S>,
+        r3: Vector4<S>,
+    ) -> Matrix4<S> {
+        let Vector4 { x: r0c0, y: r0c1, z: r0c2, w: r0c3 } = r0;
+        let Vector4 { x: r1c0, y: r1c1, z: r1c2, w: r1c3 } = r1;
+        let Vector4 { x: r2c0, y: r2c1, z: r2c2, w: r2c3 } = r2;
+        let Vector4 { x: r3c0, y: r3c1, z: r3c2, w: r3c3 } = r3;
+        Matrix4::new(
+            r0c0, r1c0, r2c0, r3c0,
+            r0c1, r1c1, r2c1, r3c1,
+            r0c2, r1c2, r2c2, r3c2,
+            r0c3, r1c3, r2c3, r3c3,
+        )
+    }
 }
 
 impl<S: BaseFloat> Matrix4<S> {
@@ -326,6 +571,15 @@ impl<S: BaseFloat> Matrix4<S> {
         )
     }
 
+    /// Create a homogeneous transformation matrix that applies the linear
+    /// transformation `m`, followed by a translation.
+    #[inline]
+    pub fn from_matrix3_translation(m: Matrix3<S>, v: Vector3<S>) -> Matrix4<S> {
+        let mut result = Matrix4::from(m);
+        result.w = v.extend(S::one());
+        result
+    }
+
     /// Create a homogeneous transformation matrix from a scale value.
     #[inline]
     pub fn from_scale(value: S) -> Matrix4<S> {
@@ -344,13 +598,49 @@ impl<S: BaseFloat> Matrix4<S> {
         )
     }
 
+    /// Create a homogeneous transformation matrix from a set of shear factors.
+    ///
+    /// Each factor is named after the axis it displaces followed by the axis
+    /// it is proportional to, so `xy` is how far `x` moves per unit of `y`.
+    /// For example, a point `(x, y, z)` is transformed to
+    /// `(x + xy * y + xz * z, yx * x + y + yz * z, zx * x + zy * y + z)`.
+    #[inline]
+    #[rustfmt::skip]
+    pub fn from_shear(xy: S, xz: S, yx: S, yz: S, zx: S, zy: S) -> Matrix4<S> {
+        Matrix4::new(
+            S::one(), yx, zx, S::zero(),
+            xy, S::one(), zy, S::zero(),
+            xz, yz, S::one(), S::zero(),
+            S::zero(), S::zero(), S::zero(), S::one(),
+        )
+    }
+
+    /// Create a viewport transformation matrix, mapping normalized device
+    /// coordinates in the cube `[-1, 1]` to window coordinates.
+    ///
+    /// The `x` and `y` coordinates are mapped to the rectangle with its
+    /// corner at `(x, y)` and the given `width` and `height`, and the `z`
+    /// coordinate is mapped to the range `[near_depth, far_depth]`.
+    #[rustfmt::skip]
+    pub fn viewport(x: S, y: S, width: S, height: S, near_depth: S, far_depth: S) -> Matrix4<S> {
+        let two: S = cast(2).unwrap();
+        let half_width = width / two;
+        let half_height = height / two;
+        let half_depth = (far_depth - near_depth) / two;
+
+        Matrix4::new(
+            half_width, S::zero(), S::zero(), S::zero(),
+            S::zero(), half_height, S::zero(), S::zero(),
+            S::zero(), S::zero(), half_depth, S::zero(),
+            x + half_width, y + half_height, near_depth + half_depth, S::one(),
+        )
+    }
+
     /// Create a homogeneous transformation matrix that will cause a vector to point at
     /// `dir`, using `up` for orientation.
     #[deprecated = "Use Matrix4::look_to_rh"]
     pub fn look_at_dir(eye: Point3<S>, dir: Vector3<S>, up: Vector3<S>) -> Matrix4<S> {
-        let f = dir.normalize();
-        let s = f.cross(up).normalize();
-        let u = s.cross(f);
+        let (f, s, u) = look_basis(dir, up);
 
         #[cfg_attr(rustfmt, rustfmt_skip)]
         Matrix4::new(
@@ -363,10 +653,12 @@ impl<S: BaseFloat> Matrix4<S> {
 
     /// Create a homogeneous transformation matrix that will cause a vector to point at
     /// `dir`, using `up` for orientation.
+    ///
+    /// If `dir` is zero, the matrix looks along `-z`. If `up` is zero or parallel to `dir`,
+    /// whichever of the `y` and `z` axes is further from `dir` is used in its place. Only
+    /// the directions of `dir` and `up` matter, so short vectors are not degenerate.
     pub fn look_to_rh(eye: Point3<S>, dir: Vector3<S>, up: Vector3<S>) -> Matrix4<S> {
-        let f = dir.normalize();
-        let s = f.cross(up).normalize();
-        let u = s.cross(f);
+        let (f, s, u) = look_basis(dir, up);
 
         #[cfg_attr(rustfmt, rustfmt_skip)]
         Matrix4::new(
@@ -379,6 +671,8 @@ impl<S: BaseFloat> Matrix4<S> {
 
     /// Create a homogeneous transformation matrix that will cause a vector to point at
     /// `dir`, using `up` for orientation.
+    ///
+    /// Degenerate inputs are handled as in `look_to_rh`.
     pub fn look_to_lh(eye: Point3<S>, dir: Vector3<S>, up: Vector3<S>) -> Matrix4<S> {
         Matrix4::look_to_rh(eye, -dir, up)
     }
@@ -392,12 +686,18 @@ impl<S: BaseFloat> Matrix4<S> {
 
     /// Create a homogeneous transformation matrix that will cause a vector to point at
     /// `center`, using `up` for orientation.
+    ///
+    /// This is a right handed view matrix, mapping `eye` to the origin and `center` onto
+    /// the `-z` axis. See `look_to_rh` for how degenerate inputs are handled, such as
+    /// `eye` being equal to `center`.
     pub fn look_at_rh(eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> Matrix4<S> {
         Matrix4::look_to_rh(eye, center - eye, up)
     }
 
     /// Create a homogeneous transformation matrix that will cause a vector to point at
     /// `center`, using `up` for orientation.
+    ///
+    /// See `look_to_lh` for how degenerate inputs are handled.
     pub fn look_at_lh(eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> Matrix4<S> {
         Matrix4::look_to_lh(eye, center - eye, up)
     }
@@ -472,6 +772,99 @@ impl<S: BaseFloat> Matrix4<S> {
         )
     }
 
+    /// Invert an affine transformation matrix, such as one composed of
+    /// rotations, scales, shears and translations. Returns `None` if the
+    /// matrix is not invertible.
+    ///
+    /// This is cheaper than `SquareMatrix::invert`, but gives the wrong
+    /// result if the bottom row of the matrix is not `[0, 0, 0, 1]`, as for a
+    /// projection.
+    pub fn invert_affine(&self) -> Option<Matrix4<S>> {
+        Matrix3::from(*self).invert().map(|linear| {
+            let translation = -(linear * self.w.truncate());
+            Matrix4::from_matrix3_translation(linear, translation)
+        })
+    }
+
+    /// Invert a rigid transformation matrix, composed of only rotations and
+    /// translations.
+    ///
+    /// This is cheaper than `invert_affine`, since the inverse of a rotation
+    /// is its transpose. In debug builds, this panics if the top-left 3 x 3
+    /// corner of the matrix is not orthonormal.
+    pub fn invert_rigid(&self) -> Matrix4<S> {
+        let rotation = Matrix3::from(*self);
+        let inverse = rotation.transpose();
+        debug_assert!(
+            abs_diff_eq!(
+                inverse * rotation,
+                Matrix3::identity(),
+                epsilon = cast(1.0e-4).unwrap()
+            ),
+            "The rotation part of the matrix is not orthonormal, found: {:?}",
+            rotation
+        );
+        Matrix4::from_matrix3_translation(inverse, -(inverse * self.w.truncate()))
+    }
+
+    /// Compute the matrix for transforming surface normals, which is the
+    /// inverse transpose of the top-left 3 x 3 corner of this matrix.
+    ///
+    /// Unlike `Matrix3::from(m)`, this keeps normals perpendicular to the
+    /// surface under non-uniform scaling. The result is not normalized, so
+    /// transformed normals may need to be normalized. Returns `None` if the
+    /// top-left corner is not invertible.
+    pub fn normal_matrix(&self) -> Option<Matrix3<S>> {
+        Matrix3::from(*self).invert().map(|m| m.transpose())
+    }
+
+    /// Extract the six clipping planes of the view frustum of a projection
+    /// (or combined projection and view) matrix, using the method described
+    /// by Gribb and Hartmann.
+    ///
+    /// The planes are returned in the order left, right, bottom, top, near,
+    /// far. Each plane `p` is normalized, such that `p.truncate().dot(v) +
+    /// p.w` is the signed distance from the point `v` to the plane, which is
+    /// positive on the inside of the frustum. This assumes an OpenGL style
+    /// clip space, with depth in the range `[-1, 1]`.
+    pub fn to_frustum_planes(&self) -> [Vector4<S>; 6] {
+        let normalize = |p: Vector4<S>| p / p.truncate().magnitude();
+        let (r0, r1, r2, r3) = (self.row(0), self.row(1), self.row(2), self.row(3));
+        [
+            normalize(r3 + r0),
+            normalize(r3 - r0),
+            normalize(r3 + r1),
+            normalize(r3 - r1),
+            normalize(r3 + r2),
+            normalize(r3 - r2),
+        ]
+    }
+
+    /// Decompose an affine transformation matrix into its translation,
+    /// rotation and scale, such that the matrix is equal to
+    /// `Matrix4::from_translation(t) * Matrix4::from(r) *
+    /// Matrix4::from_nonuniform_scale(s.x, s.y, s.z)`.
+    ///
+    /// If the matrix contains a reflection, the x scale is negated. Matrices
+    /// that contain shear cannot be represented this way, so decomposing them
+    /// is lossy. Returns `None` if the top-left 3 x 3 corner is not
+    /// invertible.
+    pub fn decompose(&self) -> Option<(Vector3<S>, Quaternion<S>, Vector3<S>)> {
+        let m = Matrix3::from(*self);
+        let det = m.determinant();
+        if is_singular(det, &[m.x, m.y, m.z]) {
+            return None;
+        }
+
+        let mut scale = Vector3::new(m.x.magnitude(), m.y.magnitude(), m.z.magnitude());
+        if det < S::zero() {
+            scale.x = -scale.x;
+        }
+        let rotation = Matrix3::from_cols(m.x / scale.x, m.y / scale.y, m.z / scale.z);
+
+        Some((self.w.truncate(), rotation.into(), scale))
+    }
+
     /// Are all entries in the matrix finite.
     pub fn is_finite(&self) -> bool {
         self.w.is_finite() && self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
@@ -599,6 +992,48 @@ impl<S: BaseFloat> Matrix for Matrix2<S> {
     }
 }
 
+/// The forward, side and up axes of a right handed view looking along `dir`.
+///
+/// A zero `dir` is replaced by `-z`. When `up` is zero, or within rounding of
+/// being parallel to `dir`, whichever of the `y` and `z` axes is further from
+/// `dir` is used instead. The threshold is relative to the length of `up`, so
+/// the result does not depend on the lengths of the inputs.
+fn look_basis<S: BaseFloat>(
+    dir: Vector3<S>,
+    up: Vector3<S>,
+) -> (Vector3<S>, Vector3<S>, Vector3<S>) {
+    let f = if dir.magnitude2() == S::zero() {
+        -Vector3::unit_z()
+    } else {
+        dir.normalize()
+    };
+    let s = f.cross(up);
+    let s = if s.magnitude2() <= up.magnitude2() * S::epsilon() {
+        let up = if f.y.abs() < f.z.abs() {
+            Vector3::unit_y()
+        } else {
+            Vector3::unit_z()
+        };
+        f.cross(up)
+    } else {
+        s
+    };
+    let s = s.normalize();
+    let u = s.cross(f);
+    (f, s, u)
+}
+
+/// Whether the determinant of a matrix with the given columns is negligible.
+///
+/// The magnitude of the determinant is bounded by the product of the lengths
+/// of the columns, so comparing against that bound rather than a fixed epsilon
+/// means that well-conditioned matrices with small entries stay invertible.
+fn is_singular<S: BaseFloat, V: InnerSpace<Scalar = S>>(det: S, cols: &[V]) -> bool {
+    let bound = cols.iter().fold(S::one(), |acc, col| acc * col.magnitude());
+    let n: S = cast(cols.len()).unwrap();
+    det.abs() <= bound * S::epsilon() * n
+}
+
 impl<S: BaseFloat> SquareMatrix for Matrix2<S> {
     type ColumnRow = Vector2<S>;
 
@@ -638,7 +1073,7 @@ impl<S: BaseFloat> SquareMatrix for Matrix2<S> {
     #[inline]
     fn invert(&self) -> Option<Matrix2<S>> {
         let det = self.determinant();
-        if det == S::zero() {
+        if is_singular(det, &[self.x, self.y]) {
             None
         } else {
             #[cfg_attr(rustfmt, rustfmt_skip)]
@@ -742,7 +1177,7 @@ impl<S: BaseFloat> SquareMatrix for Matrix3<S> {
 
     fn invert(&self) -> Option<Matrix3<S>> {
         let det = self.determinant();
-        if det == S::zero() {
+        if is_singular(det, &[self.x, self.y, self.z]) {
             None
         } else {
             Some(
@@ -868,7 +1303,7 @@ impl<S: BaseFloat> SquareMatrix for Matrix4<S> {
     #[cfg(not(feature = "simd"))]
     fn invert(&self) -> Option<Matrix4<S>> {
         let det = self.determinant();
-        if det == S::zero() {
+        if is_singular(det, &[self.x, self.y, self.z, self.w]) {
             None
         } else {
             let inv_det = S::one() / det;
@@ -911,7 +1346,7 @@ impl<S: BaseFloat> SquareMatrix for Matrix4<S> {
         let tmp0 = unsafe { det_sub_proc_unsafe(self, 1, 2, 3) };
         let det = tmp0.dot(Vector4::new(self[0][0], self[1][0], self[2][0], self[3][0]));
 
-        if det == S::zero() {
+        if is_singular(det, &[self.x, self.y, self.z, self.w]) {
             None
         } else {
             let inv_det = S::one() / det;
@@ -1231,6 +1666,9 @@ macro_rules! impl_matrix {
         impl<S: BaseFloat + SubAssign<S>> SubAssign<$MatrixN<S>> for $MatrixN<S> {
             fn sub_assign(&mut self, other: $MatrixN<S>) { $(self.$field -= other.$field);+ }
         }
+        impl<S: BaseFloat> MulAssign<$MatrixN<S>> for $MatrixN<S> {
+            fn mul_assign(&mut self, other: $MatrixN<S>) { *self = *self * other; }
+        }
 
         impl<S: BaseFloat> iter::Sum<$MatrixN<S>> for $MatrixN<S> {
             #[inline]
@@ -1492,13 +1930,13 @@ macro_rules! fixed_array_conversions {
             }
         }
 
-        // impl<$S> Into<[$S; ($n * $n)]> for $MatrixN<$S> {
-        //     #[inline]
-        //     fn into(self) -> [[$S; $n]; $n] {
-        //         // TODO: Not sure how to implement this...
-        //         unimplemented!()
-        //     }
-        // }
+        /// The elements are in column-major order, matching the memory layout.
+        impl<$S: Copy> From<$MatrixN<$S>> for [$S; ($n * $n)] {
+            #[inline]
+            fn from(m: $MatrixN<$S>) -> Self {
+                *AsRef::<[$S; ($n * $n)]>::as_ref(&m)
+            }
+        }
 
         impl<$S> AsRef<[$S; ($n * $n)]> for $MatrixN<$S> {
             #[inline]
@@ -1514,13 +1952,14 @@ macro_rules! fixed_array_conversions {
             }
         }
 
-        // impl<$S> From<[$S; ($n * $n)]> for $MatrixN<$S> {
-        //     #[inline]
-        //     fn from(m: [$S; ($n * $n)]) -> $MatrixN<$S> {
-        //         // TODO: Not sure how to implement this...
-        //         unimplemented!()
-        //     }
-        // }
+        /// The elements are expected in column-major order, matching the memory
+        /// layout.
+        impl<$S: Copy> From<[$S; ($n * $n)]> for $MatrixN<$S> {
+            #[inline]
+            fn from(m: [$S; ($n * $n)]) -> $MatrixN<$S> {
+                *<&$MatrixN<$S>>::from(&m)
+            }
+        }
 
         impl<'a, $S> From<&'a [$S; ($n * $n)]> for &'a $MatrixN<$S> {
             #[inline]
@@ -1620,6 +2059,14 @@ impl<S: BaseNum> From<Matrix3<S>> for Matrix4<S> {
     }
 }
 
+impl<S: BaseNum> From<Matrix4<S>> for Matrix3<S> {
+    /// Clone the elements of the top-left corner of a 4-dimensional matrix,
+    /// discarding the translation and projection parts.
+    fn from(m: Matrix4<S>) -> Matrix3<S> {
+        Matrix3::from_cols(m.x.truncate(), m.y.truncate(), m.z.truncate())
+    }
+}
+
 impl<S: BaseFloat> From<Matrix3<S>> for Quaternion<S> {
     /// Convert the matrix to a quaternion
     fn from(mat: Matrix3<S>) -> Quaternion<S> {
@@ -1684,6 +2131,96 @@ impl<S: fmt::Debug> fmt::Debug for Matrix4<S> {
     }
 }
 
+/// Format the entries of a column-major matrix one row per line, padding each
+/// column to the same width. The precision of the formatter is applied to
+/// every entry.
+fn fmt_rows<S: fmt::Display>(f: &mut fmt::Formatter, cols: &[&[S]]) -> fmt::Result {
+    let entries: Vec<Vec<String>> = cols
+        .iter()
+        .map(|col| {
+            col.iter()
+                .map(|x| match f.precision() {
+                    Some(precision) => format!("{:.*}", precision, x),
+                    None => format!("{}", x),
+                })
+                .collect()
+        })
+        .collect();
+    let widths: Vec<usize> = entries
+        .iter()
+        .map(|col| col.iter().map(|x| x.len()).max().unwrap_or(0))
+        .collect();
+
+    for r in 0..cols.len() {
+        if r > 0 {
+            writeln!(f)?;
+        }
+        write!(f, "[")?;
+        for (c, col) in entries.iter().enumerate() {
+            if c > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{:>width$}", col[r], width = widths[c])?;
+        }
+        write!(f, "]")?;
+    }
+    Ok(())
+}
+
+/// Format the entries of a column-major matrix on a single line, as a list of
+/// rows.
+fn to_string_flat<S: fmt::Display>(cols: &[&[S]]) -> String {
+    let rows: Vec<String> = (0..cols.len())
+        .map(|r| {
+            let row: Vec<String> = cols.iter().map(|col| col[r].to_string()).collect();
+            format!("[{}]", row.join(", "))
+        })
+        .collect();
+    format!("[{}]", rows.join(", "))
+}
+
+impl<S: fmt::Display> fmt::Display for Matrix2<S> {
+    /// Formats the matrix one row per line, with the columns aligned.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_rows(f, &[&self.x[..], &self.y[..]])
+    }
+}
+
+impl<S: fmt::Display> fmt::Display for Matrix3<S> {
+    /// Formats the matrix one row per line, with the columns aligned.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_rows(f, &[&self.x[..], &self.y[..], &self.z[..]])
+    }
+}
+
+impl<S: fmt::Display> fmt::Display for Matrix4<S> {
+    /// Formats the matrix one row per line, with the columns aligned.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_rows(f, &[&self.x[..], &self.y[..], &self.z[..], &self.w[..]])
+    }
+}
+
+impl<S: fmt::Display> Matrix2<S> {
+    /// Format the matrix on a single line, as a list of rows.
+    pub fn to_string_flat(&self) -> String {
+        to_string_flat(&[&self.x[..], &self.y[..]])
+    }
+}
+
+impl<S: fmt::Display> Matrix3<S> {
+    /// Format the matrix on a single line, as a list of rows.
+    pub fn to_string_flat(&self) -> String {
+        to_string_flat(&[&self.x[..], &self.y[..], &self.z[..]])
+    }
+}
+
+impl<S: fmt::Display> Matrix4<S> {
+    /// Format the matrix on a single line, as a list of rows.
+    pub fn to_string_flat(&self) -> String {
+        to_string_flat(&[&self.x[..], &self.y[..], &self.z[..], &self.w[..]])
+    }
+}
+
 #[cfg(feature = "rand")]
 impl<S> Distribution<Matrix2<S>> for Standard
 where
@@ -1732,6 +2269,45 @@ where
     }
 }
 
+#[cfg(feature = "rand")]
+impl<S: BaseFloat> Matrix3<S>
+where
+    Standard: Distribution<S>,
+{
+    /// Samples a rotation matrix uniformly from the space of rotations, using
+    /// `Quaternion::rand_unit`.
+    pub fn rand_rotation<R: Rng + ?Sized>(rng: &mut R) -> Matrix3<S> {
+        Quaternion::rand_unit(rng).into()
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<S: BaseFloat> Matrix4<S>
+where
+    Standard: Distribution<S>,
+{
+    /// Samples an invertible affine transformation matrix, made up of a
+    /// scale, followed by a rotation, followed by a translation.
+    ///
+    /// The rotation is sampled uniformly using `Matrix3::rand_rotation`, the
+    /// scale along each axis uniformly from `[0.5, 2)`, and each component of
+    /// the translation uniformly from `[-1, 1)`.
+    pub fn rand_affine<R: Rng + ?Sized>(rng: &mut R) -> Matrix4<S> {
+        let half: S = cast(0.5f64).unwrap();
+        let three_halves: S = cast(1.5f64).unwrap();
+        let two: S = cast(2).unwrap();
+
+        let rotation = Matrix3::rand_rotation(rng);
+        let scale =
+            Vector3::new(rng.gen(), rng.gen(), rng.gen()).map(|x: S| half + x * three_halves);
+        let translation =
+            Vector3::new(rng.gen(), rng.gen(), rng.gen()).map(|x: S| x * two - S::one());
+
+        Matrix4::from_matrix3_translation(rotation, translation)
+            * Matrix4::from_nonuniform_scale(scale.x, scale.y, scale.z)
+    }
+}
+
 // Sub procedure for SIMD when dealing with determinant and inversion
 #[inline]
 unsafe fn det_sub_proc_unsafe<S: BaseFloat>(
@@ -42,6 +42,87 @@ pub fn perspective<S: BaseFloat, A: Into<Rad<S>>>(
     .into()
 }
 
+/// Create a perspective projection matrix with the far plane at infinity.
+///
+/// The near plane is mapped to a depth of `-1` and depth approaches `1` as the
+/// distance from the camera increases, as for `perspective`.
+#[rustfmt::skip]
+pub fn perspective_infinite<S: BaseFloat, A: Into<Rad<S>>>(
+    fovy: A,
+    aspect: S,
+    near: S,
+) -> Matrix4<S> {
+    let fovy = fovy.into();
+    assert_perspective_fov(fovy, aspect, near);
+
+    let two: S = cast(2).unwrap();
+    let f = Rad::cot(fovy / two);
+
+    Matrix4::new(
+        f / aspect, S::zero(), S::zero(), S::zero(),
+        S::zero(), f, S::zero(), S::zero(),
+        S::zero(), S::zero(), -S::one(), -S::one(),
+        S::zero(), S::zero(), -two * near, S::zero(),
+    )
+}
+
+/// Create a perspective projection matrix with a reversed depth range.
+///
+/// Unlike `perspective`, the depth is in the range `[0, 1]` and decreases with
+/// distance, so the near plane is mapped to a depth of `1` and the far plane to
+/// `0`. Combined with a floating point depth buffer, this gives much more even
+/// precision across the view.
+#[rustfmt::skip]
+pub fn perspective_reversed_z<S: BaseFloat, A: Into<Rad<S>>>(
+    fovy: A,
+    aspect: S,
+    near: S,
+    far: S,
+) -> Matrix4<S> {
+    let fovy = fovy.into();
+    assert_perspective_fov(fovy, aspect, near);
+    assert!(
+        far > near,
+        "The far plane must be further than the near plane, found: far: {:?}, near: {:?}",
+        far,
+        near
+    );
+
+    let two: S = cast(2).unwrap();
+    let f = Rad::cot(fovy / two);
+    let depth = far - near;
+
+    Matrix4::new(
+        f / aspect, S::zero(), S::zero(), S::zero(),
+        S::zero(), f, S::zero(), S::zero(),
+        S::zero(), S::zero(), near / depth, -S::one(),
+        S::zero(), S::zero(), far * near / depth, S::zero(),
+    )
+}
+
+fn assert_perspective_fov<S: BaseFloat>(fovy: Rad<S>, aspect: S, near: S) {
+    assert!(
+        fovy > Rad::zero(),
+        "The vertical field of view cannot be below zero, found: {:?}",
+        fovy
+    );
+    assert!(
+        fovy < Rad::turn_div_2(),
+        "The vertical field of view cannot be greater than a half turn, found: {:?}",
+        fovy
+    );
+    assert!(
+        abs_diff_ne!(aspect.abs(), S::zero()),
+        "The absolute aspect ratio cannot be zero, found: {:?}",
+        aspect.abs()
+    );
+    assert!(
+        near > S::zero(),
+        "The near plane distance cannot be below zero, found: {:?}",
+        near
+    );
+}
+
 /// Create a perspective matrix from a view frustum.
 ///
 /// This is the equivalent of the now deprecated [`glFrustum`] function.
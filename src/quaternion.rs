@@ -33,7 +33,7 @@ use num::{BaseFloat, BaseNum};
 use point::Point3;
 use quaternion;
 use rotation::{Basis3, Rotation, Rotation3};
-use vector::Vector3;
+use vector::{Vector3, Vector4};
 
 #[cfg(feature = "mint")]
 use mint;
@@ -67,6 +67,24 @@ impl<S> Quaternion<S> {
     }
 }
 
+impl<S: Clone> Quaternion<S> {
+    /// Construct a quaternion from a slice with the components in the order
+    /// `[x, y, z, w]`, matching the array conversions. Returns `None` if the
+    /// slice does not have exactly four elements.
+    #[inline]
+    pub fn from_slice(slice: &[S]) -> Option<Quaternion<S>> {
+        match slice {
+            [xi, yj, zk, w] => Some(Quaternion::new(
+                w.clone(),
+                xi.clone(),
+                yj.clone(),
+                zk.clone(),
+            )),
+            _ => None,
+        }
+    }
+}
+
 impl<S: BaseFloat> Quaternion<S> {
     /// Construct a new quaternion as a closest arc between two vectors
     ///
@@ -97,16 +115,135 @@ impl<S: BaseFloat> Quaternion<S> {
         }
     }
 
+    /// Construct a quaternion from Euler angles applied in the XYZ order,
+    /// the same as `Quaternion::from(euler)`.
+    ///
+    /// This is equivalent to
+    /// `Quaternion::from_angle_x(x) * Quaternion::from_angle_y(y) * Quaternion::from_angle_z(z)`.
+    pub fn from_euler_xyz<A: Into<Rad<S>>>(euler: Euler<A>) -> Quaternion<S> {
+        Quaternion::from(Euler::new(euler.x.into(), euler.y.into(), euler.z.into()))
+    }
+
+    /// Construct a quaternion from Euler angles applied in the ZYX order,
+    /// also known as yaw, pitch and roll.
+    ///
+    /// This is equivalent to
+    /// `Quaternion::from_angle_z(z) * Quaternion::from_angle_y(y) * Quaternion::from_angle_x(x)`.
+    pub fn from_euler_zyx<A: Into<Rad<S>>>(euler: Euler<A>) -> Quaternion<S> {
+        let half = cast(0.5f64).unwrap();
+        let (s_x, c_x) = Rad::sin_cos(euler.x.into() * half);
+        let (s_y, c_y) = Rad::sin_cos(euler.y.into() * half);
+        let (s_z, c_z) = Rad::sin_cos(euler.z.into() * half);
+
+        Quaternion::new(
+            c_x * c_y * c_z + s_x * s_y * s_z,
+            s_x * c_y * c_z - c_x * s_y * s_z,
+            c_x * s_y * c_z + s_x * c_y * s_z,
+            c_x * c_y * s_z - s_x * s_y * c_z,
+        )
+    }
+
     /// The conjugate of the quaternion.
     #[inline]
     pub fn conjugate(self) -> Quaternion<S> {
         Quaternion::from_sv(self.s, -self.v)
     }
 
+    /// Returns whichever of the quaternion and its negation has a positive
+    /// scalar part. Both represent the same rotation, so this gives a unique
+    /// representation, which is useful before quantizing or comparing
+    /// rotations.
+    ///
+    /// If the scalar part is zero, the first non-zero component of the vector
+    /// part is made positive instead.
+    ///
+    /// ```
+    /// use cgmath::Quaternion;
+    ///
+    /// let track = [Quaternion::new(0.6, 0.0, 0.8, 0.0), Quaternion::new(-0.6, 0.0, -0.8, 0.0)];
+    /// let track: Vec<_> = track.iter().map(|q| q.canonicalize()).collect();
+    /// assert_eq!(track[0], track[1]);
+    /// ```
+    pub fn canonicalize(self) -> Quaternion<S> {
+        let sign = [self.s, self.v.x, self.v.y, self.v.z]
+            .iter()
+            .find(|&&c| c != S::zero())
+            .map_or(S::zero(), |&c| c);
+        if sign < S::zero() {
+            -self
+        } else {
+            self
+        }
+    }
+
+    /// Extract the axis and angle of the rotation represented by this
+    /// quaternion, such that `Quaternion::from_axis_angle(axis, angle)`
+    /// recovers it. The quaternion should be normalized first.
+    ///
+    /// The angle is in the range `[0, full_turn]`. If the quaternion has no
+    /// rotation, the angle is zero and the axis is `Vector3::unit_x()`.
+    pub fn to_axis_angle(self) -> (Vector3<S>, Rad<S>) {
+        let sin_half = self.v.magnitude();
+        if ulps_eq!(sin_half, &S::zero()) {
+            return (Vector3::unit_x(), Rad::zero());
+        }
+
+        // `atan2` remains accurate for rotations of close to half a turn,
+        // where `acos(s)` would lose precision.
+        let angle = Rad::atan2(sin_half, self.s) * cast(2).unwrap();
+        (self.v / sin_half, angle)
+    }
+
+    /// The angle of the rotation taking the quaternion to `other`, in the
+    /// range `[0, turn_div_2]`. Both quaternions should be normalized first.
+    ///
+    /// Since `q` and `-q` represent the same rotation, the angle between them
+    /// is zero. This differs from `InnerSpace::angle`, which treats
+    /// quaternions as four dimensional vectors.
+    pub fn angle_between(self, other: Quaternion<S>) -> Rad<S> {
+        // stay within the domain of acos()
+        let robust_dot = self.dot(other).abs().min(S::one());
+        Rad::acos(robust_dot) * cast(2).unwrap()
+    }
+
+    /// Advance the orientation by rotating with `angular_velocity`, in
+    /// radians per unit of time about the world axes, for a time step of
+    /// `dt`.
+    ///
+    /// This uses the exponential map, so a constant angular velocity is
+    /// integrated exactly regardless of the size of the step. The result is
+    /// normalized to prevent drift from accumulating over many steps.
+    pub fn integrate(self, angular_velocity: Vector3<S>, dt: S) -> Quaternion<S> {
+        let half_dt = dt / cast(2).unwrap();
+        let step = Quaternion::from_sv(S::zero(), angular_velocity * half_dt).exp();
+        (step * self).normalize()
+    }
+
+    /// Decompose the rotation into a `twist` about `axis` followed by a
+    /// `swing` about an axis perpendicular to it, returning
+    /// `(swing, twist)` such that `swing * twist` is the original rotation.
+    ///
+    /// The quaternion and the axis should be normalized first. If the
+    /// rotation is a half turn about an axis perpendicular to `axis`, the
+    /// twist is the identity.
+    pub fn swing_twist(self, axis: Vector3<S>) -> (Quaternion<S>, Quaternion<S>) {
+        let twist = Quaternion::from_sv(self.s, self.v.project_on(axis));
+        let twist = if ulps_eq!(twist.magnitude2(), &S::zero()) {
+            Quaternion::one()
+        } else {
+            twist.normalize()
+        };
+        (self * twist.conjugate(), twist)
+    }
+
     /// Do a normalized linear interpolation with `other`, by `amount`.
     ///
     /// This takes the shortest path, so if the quaternions have a negative
     /// dot product, the interpolation will be between `self` and `-other`.
+    ///
+    /// Unlike `slerp`, the angular velocity of the interpolation is not
+    /// constant, speeding up towards the middle of the path. This is cheaper
+    /// to compute and the difference is small for nearby rotations.
     pub fn nlerp(self, mut other: Quaternion<S>, amount: S) -> Quaternion<S> {
         if self.dot(other) < S::zero() {
             other = -other;
@@ -157,11 +294,117 @@ impl<S: BaseFloat> Quaternion<S> {
         }
     }
 
+    /// Spherical cubic interpolation between the quaternion and `other`, by
+    /// `amount`, using the inner control quaternions `a` and `b`.
+    ///
+    /// Interpolating between consecutive keyframes `q[i]` and `q[i + 1]`
+    /// with `a` and `b` computed by `squad_intermediate` for `q[i]` and
+    /// `q[i + 1]` respectively gives a curve with continuous angular
+    /// velocity at the keyframes, unlike a sequence of `slerp`s. All of the
+    /// quaternions should be normalized first.
+    pub fn squad(
+        self,
+        a: Quaternion<S>,
+        b: Quaternion<S>,
+        other: Quaternion<S>,
+        amount: S,
+    ) -> Quaternion<S> {
+        let two = S::one() + S::one();
+        let path = self.slerp(other, amount);
+        let controls = a.slerp(b, amount);
+        path.slerp(controls, two * amount * (S::one() - amount))
+    }
+
+    /// Compute the inner control quaternion of `squad` for the keyframe
+    /// `current`, given the keyframes `prev` and `next` either side of it.
+    ///
+    /// At the first and last keyframes of a path, the keyframe itself can be
+    /// passed in place of the missing neighbour. All of the quaternions
+    /// should be normalized first. Neighbours in the opposite hemisphere to
+    /// `current` are negated, so the control follows the shortest path just
+    /// as `slerp` does.
+    pub fn squad_intermediate(
+        mut prev: Quaternion<S>,
+        current: Quaternion<S>,
+        mut next: Quaternion<S>,
+    ) -> Quaternion<S> {
+        if prev.dot(current) < S::zero() {
+            prev = -prev;
+        }
+        if next.dot(current) < S::zero() {
+            next = -next;
+        }
+        let inverse = current.conjugate();
+        let tangent = (inverse * next).ln() + (inverse * prev).ln();
+        let quarter: S = cast(0.25f64).unwrap();
+        current * (tangent * -quarter).exp()
+    }
+
+    /// The exponential of the quaternion.
+    ///
+    /// For a pure quaternion `(0, axis * angle / 2)` with a unit `axis`, this
+    /// is the unit quaternion rotating by `angle` about `axis`.
+    pub fn exp(self) -> Quaternion<S> {
+        let v_mag = self.v.magnitude();
+        let exp_s = self.s.exp();
+        if ulps_eq!(v_mag, &S::zero()) {
+            return Quaternion::from_sv(exp_s, self.v * exp_s);
+        }
+
+        let (sin, cos) = v_mag.sin_cos();
+        Quaternion::from_sv(exp_s * cos, self.v * (exp_s * sin / v_mag))
+    }
+
+    /// The natural logarithm of the quaternion, the inverse of `exp`.
+    ///
+    /// For a unit quaternion the result is the pure quaternion
+    /// `(0, axis * angle / 2)`, where the angle is in the range
+    /// `[0, full_turn]`. The logarithm of the zero quaternion is undefined.
+    pub fn ln(self) -> Quaternion<S> {
+        let mag = self.magnitude();
+        let v_mag = self.v.magnitude();
+        if ulps_eq!(v_mag, &S::zero()) {
+            return Quaternion::from_sv(mag.ln(), self.v / mag);
+        }
+
+        let half_angle = v_mag.atan2(self.s);
+        Quaternion::from_sv(mag.ln(), self.v * (half_angle / v_mag))
+    }
+
+    /// Raise the quaternion to the power of `t`, computed as
+    /// `(self.ln() * t).exp()`.
+    ///
+    /// For a unit quaternion this scales the angle of the rotation by `t`
+    /// while keeping its axis, so `q.powf(0.5)` is half of the rotation `q`.
+    pub fn powf(self, t: S) -> Quaternion<S> {
+        (self.ln() * t).exp()
+    }
+
     pub fn is_finite(&self) -> bool {
         self.s.is_finite() && self.v.is_finite()
     }
 }
 
+#[cfg(feature = "rand")]
+impl<S: BaseFloat> Quaternion<S>
+where
+    Standard: Distribution<S>,
+{
+    /// Samples a unit quaternion uniformly from the space of rotations.
+    ///
+    /// This uses Shoemake's construction from three uniform samples. Unlike
+    /// sampling each component independently and normalizing, the resulting
+    /// rotations are not biased towards any orientation.
+    pub fn rand_unit<R: Rng + ?Sized>(rng: &mut R) -> Quaternion<S> {
+        let (u1, u2, u3): (S, S, S) = (rng.gen(), rng.gen(), rng.gen());
+        let r1 = (S::one() - u1).sqrt();
+        let r2 = u1.sqrt();
+        let (sin2, cos2) = Rad::sin_cos(Rad::full_turn() * u2);
+        let (sin3, cos3) = Rad::sin_cos(Rad::full_turn() * u3);
+        Quaternion::new(r2 * cos3, r1 * sin2, r1 * cos2, r2 * sin3)
+    }
+}
+
 impl<S: BaseFloat> Zero for Quaternion<S> {
     #[inline]
     fn zero() -> Quaternion<S> {
@@ -517,6 +760,9 @@ impl<S: BaseFloat> Rotation for Quaternion<S> {
         self * vec
     }
 
+    /// The inverse is the conjugate divided by the squared magnitude, so it is
+    /// correct for non-unit quaternions. Inverting the zero quaternion results
+    /// in `NaN` components.
     #[inline]
     fn invert(&self) -> Quaternion<S> {
         self.conjugate() / self.magnitude2()
@@ -576,6 +822,25 @@ impl<'a, S: BaseNum> From<&'a mut [S; 4]> for &'a mut Quaternion<S> {
     }
 }
 
+impl<S: BaseNum> From<Quaternion<S>> for Vector4<S> {
+    /// Convert the quaternion to a vector with the components in the order
+    /// `(x, y, z, w)`, where `w` is the scalar part. This matches the memory
+    /// layout of `Quaternion` and the order used by the array conversions.
+    #[inline]
+    fn from(v: Quaternion<S>) -> Self {
+        Vector4::new(v.v.x, v.v.y, v.v.z, v.s)
+    }
+}
+
+impl<S: BaseNum> From<Vector4<S>> for Quaternion<S> {
+    /// Convert a vector with the components in the order `(x, y, z, w)`,
+    /// where `w` is the scalar part, to a quaternion.
+    #[inline]
+    fn from(v: Vector4<S>) -> Quaternion<S> {
+        Quaternion::new(v.w, v.x, v.y, v.z)
+    }
+}
+
 impl<S: BaseNum> From<Quaternion<S>> for (S, S, S, S) {
     #[inline]
     fn from(v: Quaternion<S>) -> Self {
@@ -694,6 +959,7 @@ impl_bytemuck_cast!(Quaternion);
 
 #[cfg(test)]
 mod tests {
+    use angle::Deg;
     use quaternion::*;
     use vector::*;
 
@@ -771,12 +1037,183 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_conjugate() {
+        let q = Quaternion::new(1.0f64, 2.0, -3.0, 4.0);
+        assert_eq!(q.conjugate(), Quaternion::new(1.0, -2.0, 3.0, -4.0));
+        assert_eq!(q.conjugate().conjugate(), q);
+    }
+
+    #[test]
+    fn test_dot() {
+        let q = Quaternion::new(1.0f64, 2.0, -3.0, 4.0);
+        let r = Quaternion::new(0.5f64, -1.0, 1.0, 2.0);
+        assert_eq!(q.dot(r), 0.5 - 2.0 - 3.0 + 8.0);
+        assert_eq!(q.dot(q), q.magnitude2());
+    }
+
+    #[test]
+    fn test_invert() {
+        let unit = Quaternion::from_axis_angle(Vector3::new(0.0f64, 0.6, 0.8), Deg(70.0));
+        assert_ulps_eq!(unit * unit.invert(), Quaternion::one());
+        assert_ulps_eq!(unit.invert(), unit.conjugate());
+
+        let q = Quaternion::new(1.0f64, 2.0, -3.0, 4.0);
+        assert_ulps_eq!(q * q.invert(), Quaternion::one());
+        assert_ulps_eq!(q.invert() * q, Quaternion::one());
+
+        let zero = Quaternion::<f64>::zero().invert();
+        assert!(zero.s.is_nan() && zero.v.x.is_nan());
+    }
+
+    #[test]
+    fn test_normalize() {
+        let q = Quaternion::new(1.0f64, 2.0, -3.0, 4.0);
+        assert_ulps_eq!(q.magnitude2(), 30.0);
+        assert_ulps_eq!(q.magnitude(), 30.0f64.sqrt());
+        assert!(!q.is_normalized());
+        assert!(q.normalize().is_normalized());
+        assert!(Quaternion::<f64>::one().is_normalized());
+
+        let zero = Quaternion::<f64>::zero();
+        assert!(!zero.normalize().is_finite());
+        assert_eq!(zero.checked_normalize(), zero);
+    }
+
+    #[test]
+    fn test_normalize_drifted() {
+        let v = Vector3::new(1.0f64, 2.0, 3.0);
+        let step = Quaternion::from_axis_angle(Vector3::new(0.0, 0.6, 0.8), Deg(1.0));
+        let expected = Quaternion::from_axis_angle(Vector3::new(0.0, 0.6, 0.8), Deg(360.0));
+
+        let mut q = Quaternion::one();
+        for _ in 0..360 {
+            q = q * step * 1.0001;
+        }
+        assert!(!q.is_normalized());
+        assert!(!q.is_normalized_within(1.0e-3));
+        assert!(q.is_normalized_within(0.1));
+        q.normalize_self();
+        assert!(q.is_normalized());
+        assert!(q.is_normalized_within(1.0e-12));
+        assert_relative_eq!(q * v, expected * v, epsilon = 1.0e-9);
+    }
+
+    #[test]
+    fn test_normalize_self() {
+        let mut q = Quaternion::new(1.0f64, 2.0, -3.0, 4.0);
+        q.normalize_self();
+        assert_eq!(q, Quaternion::new(1.0f64, 2.0, -3.0, 4.0).normalize());
+        assert!(q.is_normalized());
+
+        let mut one = Quaternion::<f64>::one();
+        one.normalize_self();
+        assert_eq!(one, Quaternion::one());
+
+        let mut zero = Quaternion::<f64>::zero();
+        zero.normalize_self();
+        assert!(!zero.is_finite());
+    }
+
     #[test]
     fn test_nlerp_same() {
         let q = Quaternion::from([0.5, 0.5, 0.5, 0.5]);
         assert_ulps_eq!(q, q.nlerp(q, 0.1234));
     }
 
+    #[test]
+    fn test_canonicalize() {
+        let v = Vector3::new(1.0f64, 2.0, 3.0);
+        let rotations = [
+            Quaternion::from_axis_angle(Vector3::new(0.6, 0.0, -0.8), Deg(70.0)),
+            Quaternion::from_axis_angle(Vector3::new(0.6, 0.0, -0.8), Deg(290.0)),
+            Quaternion::new(0.0, 0.0, 0.6, -0.8),
+            Quaternion::new(0.0, 0.0, -0.6, 0.8),
+            Quaternion::new(0.0, 0.0, 0.0, -1.0),
+        ];
+        for &q in rotations.iter() {
+            let canonical = q.canonicalize();
+            assert_eq!(canonical, (-q).canonicalize());
+            assert_eq!(canonical.canonicalize(), canonical);
+            assert!(canonical == q || canonical == -q);
+            assert_ulps_eq!(canonical * v, q * v);
+        }
+        assert_eq!(rotations[2].canonicalize(), rotations[3].canonicalize());
+        assert!(rotations[1].canonicalize().s > 0.0);
+    }
+
+    #[test]
+    fn test_angle_between() {
+        let axis = Vector3::new(1.0f64, 2.0, -2.0).normalize();
+        let q = Quaternion::from_axis_angle(axis, Deg(30.0));
+        let r = Quaternion::from_axis_angle(axis, Deg(120.0));
+
+        assert_eq!(q.angle_between(q), Rad(0.0));
+        assert_eq!(q.angle_between(q * (1.0 + 1.0e-15)), Rad(0.0));
+        assert_ulps_eq!(q.angle_between(-q), Rad(0.0));
+        assert_ulps_eq!(q.angle_between(r), Deg(90.0).into());
+        assert_ulps_eq!(r.angle_between(q), Deg(90.0).into());
+        assert_ulps_eq!(q.angle_between(-r), Deg(90.0).into());
+
+        let r = Quaternion::from_axis_angle(axis, Deg(300.0));
+        assert_ulps_eq!(q.angle_between(r), Deg(90.0).into());
+    }
+
+    #[test]
+    fn test_integrate() {
+        let angular_velocity = Vector3::new(0.3f64, -1.2, 0.4);
+        let start = Quaternion::from_axis_angle(Vector3::unit_y(), Deg(30.0));
+        let (steps, dt) = (1000, 0.005);
+
+        let mut q = start;
+        for _ in 0..steps {
+            q = q.integrate(angular_velocity, dt);
+        }
+        let time = steps as f64 * dt;
+        let expected = Quaternion::from_axis_angle(
+            angular_velocity.normalize(),
+            Rad(angular_velocity.magnitude() * time),
+        ) * start;
+
+        assert!(q.is_normalized());
+        assert_relative_eq!(q, expected, epsilon = 1.0e-10);
+        assert_eq!(q.integrate(Vector3::zero(), dt), q);
+    }
+
+    #[test]
+    fn test_swing_twist() {
+        let axes = [
+            Vector3::<f64>::unit_x(),
+            Vector3::unit_z(),
+            Vector3::new(1.0, 2.0, -2.0).normalize(),
+            Vector3::new(-0.3, 0.1, 0.9).normalize(),
+        ];
+        for i in 0..24 {
+            let i = i as f64;
+            let rotation_axis = Vector3::new(i.sin(), (i * 2.3).cos(), (i * 0.7).sin()).normalize();
+            let q = Quaternion::from_axis_angle(rotation_axis, Deg(i * 37.0 - 400.0));
+            for &axis in axes.iter() {
+                let (swing, twist) = q.swing_twist(axis);
+                assert_relative_eq!(swing * twist, q, epsilon = 1.0e-12);
+                assert_relative_eq!(twist.v.cross(axis), Vector3::zero(), epsilon = 1.0e-12);
+                assert_relative_eq!(swing.v.dot(axis), 0.0, epsilon = 1.0e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_swing_twist_perpendicular() {
+        let q = Quaternion::from_axis_angle(Vector3::<f64>::unit_y(), Deg(180.0));
+        let (swing, twist) = q.swing_twist(Vector3::unit_x());
+        assert_eq!(twist, Quaternion::one());
+        assert_eq!(swing, q);
+
+        let q = Quaternion::from_axis_angle(Vector3::unit_y(), Deg(90.0));
+        let (swing, twist) = q.swing_twist(Vector3::unit_x());
+        assert_ulps_eq!(twist, Quaternion::one());
+        assert_ulps_eq!(swing, q);
+    }
+
     #[test]
     fn test_nlerp_start() {
         let q = Quaternion::from([0.5f64.sqrt(), 0.0, 0.5f64.sqrt(), 0.0]);
@@ -866,6 +1303,19 @@ mod tests {
         assert_ulps_eq!(expected, q.nlerp(r, -1.0));
     }
 
+    #[test]
+    fn test_nlerp_unit_length() {
+        let q = Quaternion::from_axis_angle(Vector3::new(0.0f64, 0.6, 0.8), Deg(30.0));
+        let r = Quaternion::from_axis_angle(Vector3::new(1.0f64, 0.0, 0.0), Deg(-150.0));
+        for i in 0..=10 {
+            let amount = i as f64 / 10.0;
+            assert_ulps_eq!(q.nlerp(r, amount).magnitude(), 1.0);
+        }
+
+        assert_ulps_eq!(q.nlerp(r, 0.0), q.slerp(r, 0.0));
+        assert_ulps_eq!(q.nlerp(r, 1.0), q.slerp(r, 1.0));
+    }
+
     #[test]
     fn test_slerp_same() {
         let q = Quaternion::from([0.5, 0.5, 0.5, 0.5]);
@@ -956,6 +1406,115 @@ mod tests {
         assert_ulps_eq!(expected, q.slerp(r, -1.0));
     }
 
+    #[test]
+    fn test_slerp_half_axis_angle() {
+        let axis = Vector3::new(1.0f64, 2.0, -2.0).normalize();
+        let q = Quaternion::from_axis_angle(axis, Deg(20.0));
+        let r = Quaternion::from_axis_angle(axis, Deg(140.0));
+        let expected = Quaternion::from_axis_angle(axis, Deg(80.0));
+
+        assert_ulps_eq!(expected, q.slerp(r, 0.5));
+        // The shortest path is taken when the quaternions have a negative dot product
+        assert_ulps_eq!(expected, q.slerp(-r, 0.5));
+        assert_ulps_eq!(q, q.slerp(r, 0.0));
+        assert_ulps_eq!(r, q.slerp(r, 1.0));
+    }
+
+    #[test]
+    fn test_exp_ln() {
+        assert_ulps_eq!(Quaternion::<f64>::zero().exp(), Quaternion::one());
+        assert_ulps_eq!(Quaternion::<f64>::one().ln(), Quaternion::zero());
+
+        let axis = Vector3::new(2.0f64, -1.0, 2.0).normalize();
+        let q = Quaternion::from_axis_angle(axis, Deg(100.0));
+        let half_angle = Rad::from(Deg(50.0)).0;
+        assert_ulps_eq!(q.ln(), Quaternion::from_sv(0.0, axis * half_angle));
+        assert_ulps_eq!(q.ln().exp(), q);
+
+        let q = Quaternion::new(1.0f64, 2.0, -3.0, 0.5);
+        assert_relative_eq!(q.ln().exp(), q, epsilon = 1.0e-12);
+    }
+
+    #[test]
+    fn test_powf() {
+        let axis = Vector3::new(2.0f64, -1.0, 2.0).normalize();
+        let q = Quaternion::from_axis_angle(axis, Deg(100.0));
+
+        assert_ulps_eq!(q.powf(0.0), Quaternion::one());
+        assert_ulps_eq!(q.powf(1.0), q);
+        assert_ulps_eq!(q.powf(0.5) * q.powf(0.5), q);
+        assert_ulps_eq!(q.powf(0.25), Quaternion::from_axis_angle(axis, Deg(25.0)));
+        assert_ulps_eq!(q.powf(-1.0), q.conjugate());
+    }
+
+    #[test]
+    fn test_squad_endpoints() {
+        let q = Quaternion::from_axis_angle(Vector3::<f64>::unit_z(), Deg(20.0));
+        let r = Quaternion::from_axis_angle(Vector3::new(0.6, 0.8, 0.0), Deg(70.0));
+        let a = Quaternion::squad_intermediate(q, q, r);
+        let b = Quaternion::squad_intermediate(q, r, r);
+
+        assert_ulps_eq!(q.squad(a, b, r, 0.0), q);
+        assert_ulps_eq!(q.squad(a, b, r, 1.0), r);
+        assert_ulps_eq!(q.squad(a, b, r, 0.3).magnitude(), 1.0);
+    }
+
+    #[test]
+    fn test_squad_intermediate_sign_flipped_neighbours() {
+        let prev = Quaternion::from_axis_angle(Vector3::<f64>::unit_z(), Deg(10.0));
+        let current = Quaternion::from_axis_angle(Vector3::new(0.6, 0.0, 0.8), Deg(50.0));
+        let next = Quaternion::from_axis_angle(Vector3::unit_x(), Deg(80.0));
+        let expected = Quaternion::squad_intermediate(prev, current, next);
+
+        assert_ulps_eq!(
+            Quaternion::squad_intermediate(-prev, current, next),
+            expected
+        );
+        assert_ulps_eq!(
+            Quaternion::squad_intermediate(prev, current, -next),
+            expected
+        );
+        assert_ulps_eq!(
+            Quaternion::squad_intermediate(-prev, current, -next),
+            expected
+        );
+        assert_ulps_eq!(
+            current.squad(expected, expected, -next, 0.5),
+            current.squad(expected, expected, next, 0.5)
+        );
+    }
+
+    #[test]
+    fn test_squad_continuous_velocity() {
+        let keys = [
+            Quaternion::from_axis_angle(Vector3::<f64>::unit_z(), Deg(0.0)),
+            Quaternion::from_axis_angle(Vector3::unit_z(), Deg(40.0)),
+            Quaternion::from_axis_angle(Vector3::new(0.6, 0.0, 0.8), Deg(60.0)),
+            Quaternion::from_axis_angle(Vector3::new(0.0, 0.6, 0.8), Deg(30.0)),
+            Quaternion::from_axis_angle(Vector3::unit_x(), Deg(50.0)),
+        ];
+        let controls: Vec<_> = (1..4)
+            .map(|i| Quaternion::squad_intermediate(keys[i - 1], keys[i], keys[i + 1]))
+            .collect();
+        let before = |t| keys[1].squad(controls[0], controls[1], keys[2], t);
+        let after = |t| keys[2].squad(controls[1], controls[2], keys[3], t);
+
+        // Angular velocity, estimated from a small step either side of `keys[2]`
+        let h = 1.0e-5;
+        let velocity_before = (before(1.0) * before(1.0 - h).conjugate()).ln() / h;
+        let velocity_after = (after(h) * after(0.0).conjugate()).ln() / h;
+        assert_relative_eq!(velocity_before, velocity_after, epsilon = 1.0e-3);
+
+        // Consecutive slerps do not have continuous angular velocity
+        let velocity_before = (keys[2] * keys[1].slerp(keys[2], 1.0 - h).conjugate()).ln() / h;
+        let velocity_after = (keys[2].slerp(keys[3], h) * keys[2].conjugate()).ln() / h;
+        assert!(!relative_eq!(
+            velocity_before,
+            velocity_after,
+            epsilon = 1.0e-3
+        ));
+    }
+
     #[test]
     fn test_slerp_regression() {
         let a = Quaternion::<f32>::new(0.00052311074, 0.9999999, 0.00014682197, -0.000016342687);
@@ -258,9 +258,57 @@ where
     /// Returns the
     /// [vector projection](https://en.wikipedia.org/wiki/Vector_projection)
     /// of the current inner space projected onto the supplied argument.
+    ///
+    /// Projecting onto the zero vector returns the zero vector.
     #[inline]
     fn project_on(self, other: Self) -> Self {
-        other * (self.dot(other) / other.magnitude2())
+        let magnitude2 = other.magnitude2();
+        if magnitude2.is_zero() {
+            Self::zero()
+        } else {
+            other * (self.dot(other) / magnitude2)
+        }
+    }
+
+    /// Returns the vector rejection of the current inner space from the
+    /// supplied argument. That is, the part perpendicular to `other`, such
+    /// that `self.project_on(other) + self.reject_from(other) == self`.
+    ///
+    /// Rejecting from the zero vector returns `self`.
+    #[inline]
+    fn reject_from(self, other: Self) -> Self {
+        self - self.project_on(other)
+    }
+
+    /// Reflect the current inner space off a surface with the given
+    /// `normal`, which should be normalized. The current inner space is the
+    /// incident direction, pointing towards the surface, and the result points
+    /// away from it, matching the GLSL `reflect` function.
+    #[inline]
+    fn reflect(self, normal: Self) -> Self {
+        let two = Self::Scalar::one() + Self::Scalar::one();
+        self - normal * (two * self.dot(normal))
+    }
+
+    /// Refract the current inner space through a surface with the given
+    /// `normal`, where `eta` is the ratio of the refractive indices on the
+    /// incident side and the far side of the surface. The incident direction
+    /// and the normal should both be normalized, using the same conventions
+    /// as `reflect`, which matches the GLSL `refract` function.
+    ///
+    /// Returns `None` in the case of total internal reflection.
+    #[inline]
+    fn refract(self, normal: Self, eta: Self::Scalar) -> Option<Self>
+    where
+        Self::Scalar: Float,
+    {
+        let cos = self.dot(normal);
+        let k = Self::Scalar::one() - eta * eta * (Self::Scalar::one() - cos * cos);
+        if k < Self::Scalar::zero() {
+            None
+        } else {
+            Some(self * eta - normal * (eta * cos + k.sqrt()))
+        }
     }
 
     /// The distance from the tail to the tip of the vector.
@@ -272,6 +320,33 @@ where
         Float::sqrt(self.magnitude2())
     }
 
+    /// Returns `true` if the magnitude of the vector is approximately `1`.
+    ///
+    /// The squared magnitude is compared against `1` with `ulps_eq!`, which
+    /// allows a difference of up to 4 units in the last place. This accepts
+    /// the result of `normalize`, but little more; use
+    /// `InnerSpace::is_normalized_within` to detect drift with a looser
+    /// tolerance.
+    fn is_normalized(self) -> bool
+    where
+        Self::Scalar: approx::UlpsEq,
+    {
+        ulps_eq!(self.magnitude2(), &Self::Scalar::one())
+    }
+
+    /// Returns `true` if the squared magnitude of the vector differs from `1`
+    /// by at most `epsilon`.
+    ///
+    /// This is useful for deciding when a value that has accumulated rounding
+    /// errors, such as a product of many rotations, needs to be normalized
+    /// again.
+    fn is_normalized_within(self, epsilon: Self::Scalar) -> bool
+    where
+        Self::Scalar: Float,
+    {
+        (self.magnitude2() - Self::Scalar::one()).abs() <= epsilon
+    }
+
     /// Returns a vector with the same direction, but with a magnitude of `1`.
     ///
     /// If a vector with magnitude zero is passed in this will return a `NaN` vector. If you do not
@@ -284,6 +359,28 @@ where
         self.normalize_to(Self::Scalar::one())
     }
 
+    /// Normalizes the vector in place, as `*self = self.normalize()`.
+    ///
+    /// As with `InnerSpace::normalize`, a vector with magnitude zero becomes a
+    /// `NaN` vector.
+    #[inline]
+    fn normalize_self(&mut self)
+    where
+        Self::Scalar: Float,
+    {
+        *self = self.normalize();
+    }
+
+    /// Scales the vector in place to the given magnitude, as
+    /// `*self = self.normalize_to(magnitude)`.
+    #[inline]
+    fn normalize_self_to(&mut self, magnitude: Self::Scalar)
+    where
+        Self::Scalar: Float,
+    {
+        *self = self.normalize_to(magnitude);
+    }
+
     /// Returns a vector with the same direction and a given magnitude.
     ///
     /// If a vector with magnitude zero is passed in this will return a `NaN` vector. If you do not
@@ -327,6 +424,36 @@ where
             self.normalize_to(magnitude)
         }
     }
+
+    /// Returns a vector with the same direction, but with a magnitude of `1`,
+    /// or `None` if the vector is too small to be normalized accurately.
+    ///
+    /// This is the case when the squared magnitude is zero, subnormal,
+    /// infinite or `NaN`, where `InnerSpace::normalize` would produce `NaN` or
+    /// wildly inaccurate components.
+    #[inline]
+    fn try_normalize(self) -> Option<Self>
+    where
+        Self::Scalar: Float,
+    {
+        let magnitude2 = self.magnitude2();
+        if magnitude2.is_normal() {
+            Some(self * (Self::Scalar::one() / magnitude2.sqrt()))
+        } else {
+            None
+        }
+    }
+
+    /// Returns a vector with the same direction, but with a magnitude of `1`,
+    /// or `fallback` if the vector cannot be normalized, as determined by
+    /// `InnerSpace::try_normalize`.
+    #[inline]
+    fn normalize_or(self, fallback: Self) -> Self
+    where
+        Self::Scalar: Float,
+    {
+        self.try_normalize().unwrap_or(fallback)
+    }
 }
 
 /// Points in a [Euclidean space](https://en.wikipedia.org/wiki/Euclidean_space)
@@ -520,6 +647,15 @@ where
     /// Get a row from this matrix by-value.
     fn row(&self, r: usize) -> Self::Row;
 
+    /// Replace a row in the array.
+    fn replace_row(&mut self, r: usize, src: Self::Row) -> Self::Row {
+        let old = self.row(r);
+        for c in 0..Self::Row::len() {
+            self[c][r] = src[c];
+        }
+        old
+    }
+
     /// Swap two rows of this array.
     fn swap_rows(&mut self, a: usize, b: usize);
     /// Swap two columns of this array.
@@ -590,16 +726,89 @@ where
 
     /// Invert this matrix, returning a new matrix. `m.mul_m(m.invert())` is
     /// the identity matrix. Returns `None` if this matrix is not invertible
-    /// (has a determinant of zero).
+    /// (has a determinant that is negligible compared to the scale of its
+    /// entries).
     fn invert(&self) -> Option<Self>;
 
-    /// Test if this matrix is invertible.
-    #[inline]
-    fn is_invertible(&self) -> bool
+    /// Solve the linear system `self * x = b` for `x`, using Gaussian
+    /// elimination with partial pivoting. This is cheaper and more accurate
+    /// than computing `self.invert()` to solve a single system. Returns
+    /// `None` if this matrix is singular by the same test as `invert`.
+    ///
+    /// ```
+    /// use cgmath::prelude::*;
+    /// use cgmath::{Matrix3, Vector3};
+    ///
+    /// let m = Matrix3::new(2.0, 0.0, 1.0, 1.0, 3.0, 0.0, 0.0, 1.0, 4.0);
+    /// let x = Vector3::new(1.0, -2.0, 0.5);
+    /// assert_eq!(m.solve(m * x), Some(x));
+    /// ```
+    fn solve(&self, b: Self::ColumnRow) -> Option<Self::ColumnRow>
     where
-        Self::Scalar: approx::UlpsEq,
+        Self::Scalar: BaseFloat,
     {
-        ulps_ne!(self.determinant(), &Self::Scalar::zero())
+        let n = Self::ColumnRow::len();
+        let mut a = *self;
+        let mut x = b;
+
+        // The matrix is rejected by the same test as `invert`: the
+        // determinant, the product of the pivots, is compared against the
+        // product of the lengths of the columns.
+        let mut bound = Self::Scalar::one();
+        for c in 0..n {
+            let mut length2 = Self::Scalar::zero();
+            for r in 0..n {
+                length2 += a[c][r] * a[c][r];
+            }
+            bound *= length2.sqrt();
+        }
+        let tolerance = bound * Self::Scalar::epsilon() * cast(n).unwrap();
+        let mut det = Self::Scalar::one();
+
+        for k in 0..n {
+            let mut pivot = k;
+            for r in (k + 1)..n {
+                if a[k][r].abs() > a[k][pivot].abs() {
+                    pivot = r;
+                }
+            }
+            if a[k][pivot] == Self::Scalar::zero() {
+                return None;
+            }
+            if pivot != k {
+                a.swap_rows(k, pivot);
+                x.swap_elements(k, pivot);
+            }
+
+            for r in (k + 1)..n {
+                let factor = a[k][r] / a[k][k];
+                for c in k..n {
+                    a[c][r] = a[c][r] - factor * a[c][k];
+                }
+                x[r] = x[r] - factor * x[k];
+            }
+            det *= a[k][k];
+        }
+        if det.abs() <= tolerance {
+            return None;
+        }
+
+        for k in (0..n).rev() {
+            let mut sum = x[k];
+            for c in (k + 1)..n {
+                sum -= a[c][k] * x[c];
+            }
+            x[k] = sum / a[k][k];
+        }
+
+        Some(x)
+    }
+
+    /// Test if this matrix is invertible, that is, if `invert` returns
+    /// `Some`.
+    #[inline]
+    fn is_invertible(&self) -> bool {
+        self.invert().is_some()
     }
 
     /// Test if this matrix is the identity matrix. That is, it is diagonal
@@ -619,6 +828,22 @@ where
     /// Test if this matrix is symmetric. That is, it is equal to its
     /// transpose.
     fn is_symmetric(&self) -> bool;
+
+    /// Test if this matrix is a rotation matrix. That is, its columns are
+    /// orthonormal and its determinant is one, so it does not scale, shear or
+    /// reflect.
+    ///
+    /// Note that a `Matrix4` containing a translation is not a rotation
+    /// matrix.
+    #[inline]
+    fn is_rotation(&self) -> bool
+    where
+        Self: approx::UlpsEq,
+        Self::Scalar: approx::UlpsEq,
+    {
+        (*self * self.transpose()).is_identity()
+            && ulps_eq!(self.determinant(), &Self::Scalar::one())
+    }
 }
 
 /// Angles, and their associated trigonometric functions.
@@ -650,18 +875,24 @@ where
 {
     type Unitless: BaseFloat;
 
-    /// Return the angle, normalized to the range `[0, full_turn]`.
+    /// Return the angle, normalized to the range `[0, full_turn)`.
     #[inline]
     fn normalize(self) -> Self {
         let rem = self % Self::full_turn();
-        if rem < Self::zero() {
+        let rem = if rem < Self::zero() {
             rem + Self::full_turn()
         } else {
             rem
+        };
+        // Tiny negative angles can round up to a full turn
+        if ulps_eq!(rem, Self::full_turn()) {
+            Self::zero()
+        } else {
+            rem
         }
     }
 
-    /// Return the angle, normalized to the range `[-turn_div_2, turn_div_2]`.
+    /// Return the angle, normalized to the range `(-turn_div_2, turn_div_2]`.
     #[inline]
     fn normalize_signed(self) -> Self {
         let rem = self.normalize();
@@ -672,48 +903,241 @@ where
         }
     }
 
+    /// Returns the shortest signed angle that rotates `self` onto `other`,
+    /// in the range `(-turn_div_2, turn_div_2]`.
+    ///
+    /// ```rust
+    /// use cgmath::prelude::*;
+    /// use cgmath::Deg;
+    ///
+    /// assert_eq!(Deg(350.0).difference(Deg(10.0)), Deg(20.0));
+    /// assert_eq!(Deg(10.0).difference(Deg(350.0)), Deg(-20.0));
+    /// ```
+    #[inline]
+    fn difference(self, other: Self) -> Self {
+        (other - self).normalize_signed()
+    }
+
+    /// Linearly interpolate from `self` towards `other` by `amount`, taking
+    /// the shortest path around the circle.
+    ///
+    /// The result is not normalized, but `amount == 0` and `amount == 1`
+    /// return `self` and `other` exactly.
+    ///
+    /// ```rust
+    /// use cgmath::prelude::*;
+    /// use cgmath::Deg;
+    ///
+    /// assert_eq!(Deg(350.0).lerp(Deg(10.0), 0.5).normalize(), Deg(0.0));
+    /// ```
+    #[inline]
+    fn lerp(self, other: Self, amount: Self::Unitless) -> Self {
+        if amount == Self::Unitless::one() {
+            other
+        } else {
+            self + self.difference(other) * amount
+        }
+    }
+
+    /// Clamp the angle to the arc running anticlockwise from `min` to `max`,
+    /// returning the result normalized to the range `[0, full_turn)`.
+    ///
+    /// The arc may cross the wrap point, so `[Deg(330.0), Deg(30.0)]` describes
+    /// the sixty degrees around zero. Angles outside of the arc are snapped to
+    /// the nearest endpoint.
+    ///
+    /// ```rust
+    /// use cgmath::prelude::*;
+    /// use cgmath::Deg;
+    ///
+    /// assert_eq!(Deg(40.0).clamp(Deg(-30.0), Deg(30.0)), Deg(30.0));
+    /// assert_eq!(Deg(10.0).clamp(Deg(-30.0), Deg(30.0)), Deg(10.0));
+    /// ```
+    fn clamp(self, min: Self, max: Self) -> Self {
+        let angle = self.normalize();
+        let (min, max) = (min.normalize(), max.normalize());
+        if (angle - min).normalize() <= (max - min).normalize() {
+            return angle;
+        }
+
+        let abs = |a: Self| if a < Self::zero() { -a } else { a };
+        if abs(angle.difference(min)) <= abs(angle.difference(max)) {
+            min
+        } else {
+            max
+        }
+    }
+
+    /// Compare the normalized representations of two angles, so that
+    /// equivalent angles such as `Deg(-10.0)` and `Deg(350.0)` compare equal.
+    ///
+    /// Returns `None` if either angle is `NaN`.
+    ///
+    /// ```rust
+    /// use cgmath::prelude::*;
+    /// use cgmath::Deg;
+    /// use std::cmp::Ordering;
+    ///
+    /// assert_eq!(Deg(-10.0).cmp_wrapped(Deg(350.0)), Some(Ordering::Equal));
+    /// assert_eq!(Deg(-10.0).cmp_wrapped(Deg(10.0)), Some(Ordering::Greater));
+    /// ```
+    #[inline]
+    fn cmp_wrapped(self, other: Self) -> Option<cmp::Ordering> {
+        self.normalize().partial_cmp(&other.normalize())
+    }
+
+    /// Returns `true` if the shortest arc between the two angles is within
+    /// `epsilon`, so that angles either side of the wrap point can compare
+    /// equal.
+    ///
+    /// ```rust
+    /// use cgmath::prelude::*;
+    /// use cgmath::Deg;
+    ///
+    /// assert!(Deg(359.9999).abs_diff_eq_wrapped(Deg(0.0001), 0.001));
+    /// ```
+    #[inline]
+    fn abs_diff_eq_wrapped(self, other: Self, epsilon: Self::Unitless) -> bool {
+        approx::AbsDiffEq::abs_diff_eq(&self.difference(other), &Self::zero(), epsilon)
+    }
+
+    /// Returns the angle with the smallest normalized value.
+    #[inline]
+    fn min_wrapped(self, other: Self) -> Self {
+        if other.normalize() < self.normalize() {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the angle with the largest normalized value.
+    #[inline]
+    fn max_wrapped(self, other: Self) -> Self {
+        if self.normalize() < other.normalize() {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Advance the angle at `rate` for a time step of `dt`, returning the
+    /// result normalized to the range `[0, full_turn)`.
+    ///
+    /// ```rust
+    /// use cgmath::prelude::*;
+    /// use cgmath::Deg;
+    ///
+    /// assert_eq!(Deg(350.0).advance(Deg(40.0), 0.5), Deg(10.0));
+    /// ```
+    #[inline]
+    fn advance(self, rate: Self, dt: Self::Unitless) -> Self {
+        (self + rate * dt).normalize()
+    }
+
     /// Return the angle rotated by half a turn.
     #[inline]
     fn opposite(self) -> Self {
         Self::normalize(self + Self::turn_div_2())
     }
 
-    /// Returns the interior bisector of the two angles.
+    /// Returns the interior bisector of the two angles, halfway along the
+    /// shortest arc between them and normalized to the range `[0, full_turn)`.
+    ///
+    /// If the angles are exactly opposite each other, the bisector a quarter
+    /// turn anticlockwise from `self` is returned.
+    ///
+    /// ```rust
+    /// use cgmath::prelude::*;
+    /// use cgmath::Deg;
+    ///
+    /// assert_eq!(Deg(350.0).bisect(Deg(30.0)), Deg(10.0));
+    /// assert_eq!(Deg(0.0).bisect(Deg(180.0)), Deg(90.0));
+    /// ```
     #[inline]
     fn bisect(self, other: Self) -> Self {
         let half = cast(0.5f64).unwrap();
-        Self::normalize((self - other) * half + self)
+        Self::normalize(self + self.difference(other) * half)
+    }
+
+    /// Returns `true` if the normalized angle is greater than zero and less
+    /// than a right angle.
+    #[inline]
+    fn is_acute(self) -> bool {
+        let angle = self.normalize();
+        Self::zero() < angle && angle < Self::turn_div_4() && !angle.is_right()
+    }
+
+    /// Returns `true` if the normalized angle is approximately a right angle.
+    #[inline]
+    fn is_right(self) -> bool {
+        ulps_eq!(self.normalize(), Self::turn_div_4())
+    }
+
+    /// Returns `true` if the normalized angle is greater than a right angle
+    /// and less than a straight angle.
+    #[inline]
+    fn is_obtuse(self) -> bool {
+        let angle = self.normalize();
+        Self::turn_div_4() < angle
+            && angle < Self::turn_div_2()
+            && !angle.is_right()
+            && !angle.is_straight()
+    }
+
+    /// Returns `true` if the normalized angle is approximately half a turn.
+    #[inline]
+    fn is_straight(self) -> bool {
+        ulps_eq!(self.normalize(), Self::turn_div_2())
+    }
+
+    /// Returns `true` if the normalized angle is greater than a straight angle
+    /// and less than a full turn.
+    #[inline]
+    fn is_reflex(self) -> bool {
+        let angle = self.normalize();
+        Self::turn_div_2() < angle && !angle.is_straight()
     }
 
     /// A full rotation.
     fn full_turn() -> Self;
 
+    /// A full rotation divided into `n` equal parts.
+    ///
+    /// ```rust
+    /// use cgmath::prelude::*;
+    /// use cgmath::Deg;
+    ///
+    /// assert_eq!(Deg::full_turn_div(8), Deg(45.0));
+    /// ```
+    #[inline]
+    fn full_turn_div(n: u32) -> Self {
+        let factor: Self::Unitless = cast(n).unwrap();
+        Self::full_turn() / factor
+    }
+
     /// Half of a full rotation.
     #[inline]
     fn turn_div_2() -> Self {
-        let factor: Self::Unitless = cast(2).unwrap();
-        Self::full_turn() / factor
+        Self::full_turn_div(2)
     }
 
     /// A third of a full rotation.
     #[inline]
     fn turn_div_3() -> Self {
-        let factor: Self::Unitless = cast(3).unwrap();
-        Self::full_turn() / factor
+        Self::full_turn_div(3)
     }
 
     /// A quarter of a full rotation.
     #[inline]
     fn turn_div_4() -> Self {
-        let factor: Self::Unitless = cast(4).unwrap();
-        Self::full_turn() / factor
+        Self::full_turn_div(4)
     }
 
     /// A sixth of a full rotation.
     #[inline]
     fn turn_div_6() -> Self {
-        let factor: Self::Unitless = cast(6).unwrap();
-        Self::full_turn() / factor
+        Self::full_turn_div(6)
     }
 
     /// Compute the sine of the angle, returning a unitless ratio.
@@ -814,6 +1238,8 @@ where
 
     /// Compute the arcsine of the ratio, returning the resulting angle.
     ///
+    /// The ratio must lie within `[-1, 1]`, otherwise the result will be `NaN`.
+    ///
     /// ```rust
     /// use cgmath::prelude::*;
     /// use cgmath::Rad;
@@ -824,6 +1250,8 @@ where
 
     /// Compute the arccosine of the ratio, returning the resulting angle.
     ///
+    /// The ratio must lie within `[-1, 1]`, otherwise the result will be `NaN`.
+    ///
     /// ```rust
     /// use cgmath::prelude::*;
     /// use cgmath::Rad;
@@ -842,5 +1270,21 @@ where
     /// ```
     fn atan(ratio: Self::Unitless) -> Self;
 
+    /// Compute the four quadrant arctangent of `a` (the `y` coordinate) and
+    /// `b` (the `x` coordinate), returning the resulting angle in the range
+    /// `[-turn_div_2, turn_div_2]`.
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate approx;
+    /// # extern crate cgmath;
+    /// use cgmath::prelude::*;
+    /// use cgmath::Rad;
+    /// use std::f32;
+    ///
+    /// # fn main() {
+    /// let angle: Rad<f32> = Rad::atan2(1.0, -1.0);
+    /// assert_ulps_eq!(angle, Rad(3.0 * f32::consts::FRAC_PI_4));
+    /// # }
+    /// ```
     fn atan2(a: Self::Unitless, b: Self::Unitless) -> Self;
 }
@@ -13,21 +13,25 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use num_traits::{Bounded, Float, NumCast};
+use num_traits::{Bounded, CheckedAdd, CheckedMul, CheckedSub, Float, NumCast};
 #[cfg(feature = "rand")]
 use rand::{
     distributions::{Distribution, Standard},
     Rng,
 };
+use std::cmp::Ordering;
+use std::error;
 use std::fmt;
 use std::iter;
 use std::mem;
 use std::ops::*;
+use std::str::FromStr;
 
 use structure::*;
 
 use angle::Rad;
 use approx;
+use matrix::{Matrix2, Matrix3};
 use num::{BaseFloat, BaseNum};
 
 #[cfg(feature = "mint")]
@@ -37,7 +41,7 @@ use mint;
 ///
 /// This type is marked as `#[repr(C)]`.
 #[repr(C)]
-#[derive(PartialEq, Eq, Copy, Clone, Hash)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Vector1<S> {
     /// The x component of the vector.
@@ -48,7 +52,7 @@ pub struct Vector1<S> {
 ///
 /// This type is marked as `#[repr(C)]`.
 #[repr(C)]
-#[derive(PartialEq, Eq, Copy, Clone, Hash)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Vector2<S> {
     /// The x component of the vector.
@@ -61,7 +65,7 @@ pub struct Vector2<S> {
 ///
 /// This type is marked as `#[repr(C)]`.
 #[repr(C)]
-#[derive(PartialEq, Eq, Copy, Clone, Hash)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Vector3<S> {
     /// The x component of the vector.
@@ -76,7 +80,7 @@ pub struct Vector3<S> {
 ///
 /// This type is marked as `#[repr(C)]`.
 #[repr(C)]
-#[derive(PartialEq, Eq, Copy, Clone, Hash)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Vector4<S> {
     /// The x component of the vector.
@@ -117,6 +121,18 @@ macro_rules! impl_vector {
             {
                 $VectorN { $($field: f(self.$field, v2.$field)),+ }
             }
+
+            /// Combine the components of the vector in order, starting with
+            /// `init`, by repeatedly applying the given operation to the
+            /// accumulated value and the next component.
+            #[inline]
+            pub fn fold<B, F>(self, init: B, mut f: F) -> B
+                where F: FnMut(B, S) -> B
+            {
+                let acc = init;
+                $(let acc = f(acc, self.$field);)+
+                acc
+            }
         }
 
         /// The short constructor.
@@ -125,6 +141,227 @@ macro_rules! impl_vector {
             $VectorN::new($($field),+)
         }
 
+        impl<S: BaseNum> $VectorN<S> {
+            /// The component-wise minimum of two vectors.
+            #[inline]
+            pub fn min_element_wise(self, other: $VectorN<S>) -> $VectorN<S> {
+                self.zip(other, |a, b| if b < a { b } else { a })
+            }
+
+            /// The component-wise maximum of two vectors.
+            #[inline]
+            pub fn max_element_wise(self, other: $VectorN<S>) -> $VectorN<S> {
+                self.zip(other, |a, b| if a < b { b } else { a })
+            }
+
+            /// Clamp each component between the corresponding components of
+            /// `min` and `max`.
+            ///
+            /// In debug builds, this panics if a component of `min` is greater
+            /// than the corresponding component of `max`.
+            #[inline]
+            pub fn clamp_element_wise(self, min: $VectorN<S>, max: $VectorN<S>) -> $VectorN<S> {
+                $(debug_assert!(min.$field <= max.$field, "min is greater than max, found: {:?} > {:?}", min, max);)+
+                self.max_element_wise(min).min_element_wise(max)
+            }
+
+            /// Clamp each component between `min` and `max`.
+            ///
+            /// In debug builds, this panics if `min` is greater than `max`.
+            #[inline]
+            pub fn clamp(self, min: S, max: S) -> $VectorN<S> {
+                self.clamp_element_wise($VectorN::from_value(min), $VectorN::from_value(max))
+            }
+
+            /// The smallest component of the vector.
+            #[inline]
+            pub fn min_component(self) -> S {
+                self.fold(self.x, |a, b| if b < a { b } else { a })
+            }
+
+            /// The largest component of the vector.
+            #[inline]
+            pub fn max_component(self) -> S {
+                self.fold(self.x, |a, b| if a < b { b } else { a })
+            }
+
+            /// The index of the largest component of the vector, such as the
+            /// dominant axis of a normal. Ties resolve to the lowest index.
+            #[inline]
+            pub fn max_component_index(self) -> usize {
+                let mut i = 0;
+                let (index, _) = self.fold((0, self.x), |(index, max), x| {
+                    let current = i;
+                    i += 1;
+                    if max < x { (current, x) } else { (index, max) }
+                });
+                index
+            }
+        }
+
+        impl<S: PartialOrd> $VectorN<S> {
+            /// Whether each component is less than the corresponding component
+            /// of `other`, like the GLSL `lessThan` function.
+            #[inline]
+            pub fn lt_element_wise(self, other: $VectorN<S>) -> $VectorN<bool> {
+                self.zip(other, |a, b| a < b)
+            }
+
+            /// Whether each component is less than or equal to the
+            /// corresponding component of `other`, like the GLSL
+            /// `lessThanEqual` function.
+            #[inline]
+            pub fn le_element_wise(self, other: $VectorN<S>) -> $VectorN<bool> {
+                self.zip(other, |a, b| a <= b)
+            }
+
+            /// Whether each component is greater than the corresponding
+            /// component of `other`, like the GLSL `greaterThan` function.
+            #[inline]
+            pub fn gt_element_wise(self, other: $VectorN<S>) -> $VectorN<bool> {
+                self.zip(other, |a, b| a > b)
+            }
+
+            /// Whether each component is greater than or equal to the
+            /// corresponding component of `other`, like the GLSL
+            /// `greaterThanEqual` function.
+            #[inline]
+            pub fn ge_element_wise(self, other: $VectorN<S>) -> $VectorN<bool> {
+                self.zip(other, |a, b| a >= b)
+            }
+
+            /// Whether each component is equal to the corresponding component
+            /// of `other`, like the GLSL `equal` function.
+            #[inline]
+            pub fn eq_element_wise(self, other: $VectorN<S>) -> $VectorN<bool> {
+                self.zip(other, |a, b| a == b)
+            }
+
+            /// Whether each component is not equal to the corresponding
+            /// component of `other`, like the GLSL `notEqual` function.
+            #[inline]
+            pub fn ne_element_wise(self, other: $VectorN<S>) -> $VectorN<bool> {
+                self.zip(other, |a, b| a != b)
+            }
+        }
+
+        impl<S: CheckedAdd + CheckedSub + CheckedMul> $VectorN<S> {
+            /// Add two vectors, returning `None` if any component overflows.
+            #[inline]
+            pub fn checked_add(self, other: $VectorN<S>) -> Option<$VectorN<S>> {
+                Some($VectorN::new($(self.$field.checked_add(&other.$field)?),+))
+            }
+
+            /// Subtract two vectors, returning `None` if any component
+            /// overflows.
+            #[inline]
+            pub fn checked_sub(self, other: $VectorN<S>) -> Option<$VectorN<S>> {
+                Some($VectorN::new($(self.$field.checked_sub(&other.$field)?),+))
+            }
+
+            /// Multiply the vector by a scalar, returning `None` if any
+            /// component overflows.
+            #[inline]
+            pub fn checked_mul(self, scalar: S) -> Option<$VectorN<S>> {
+                Some($VectorN::new($(self.$field.checked_mul(&scalar)?),+))
+            }
+        }
+
+        impl<S: BaseNum + Neg<Output = S>> $VectorN<S> {
+            /// The component-wise absolute value.
+            #[inline]
+            pub fn abs(self) -> $VectorN<S> {
+                self.map(|x| if x < S::zero() { -x } else { x })
+            }
+
+            /// The component-wise sign, matching the GLSL `sign` function. Each
+            /// component is `1` if positive, `-1` if negative, and `0` if
+            /// zero. Unlike `Float::signum`, the sign of zero is zero.
+            #[inline]
+            pub fn sign(self) -> $VectorN<S> {
+                $VectorN::new($(
+                    if self.$field > S::zero() {
+                        S::one()
+                    } else if self.$field < S::zero() {
+                        -S::one()
+                    } else {
+                        self.$field
+                    }
+                ),+)
+            }
+        }
+
+        impl<S: BaseFloat> $VectorN<S> {
+            /// Linearly interpolate each component towards `other`, by the
+            /// corresponding component of `amount`, matching the GLSL `mix`
+            /// function. Like `lerp`, amounts outside of `[0, 1]`
+            /// extrapolate.
+            #[inline]
+            pub fn mix(self, other: $VectorN<S>, amount: $VectorN<S>) -> $VectorN<S> {
+                $VectorN::new($(self.$field + (other.$field - self.$field) * amount.$field),+)
+            }
+
+            /// Perform smooth Hermite interpolation of each component between
+            /// the corresponding components of `edge0` and `edge1`, matching
+            /// the GLSL `smoothstep` function. Each component of the result is
+            /// `0` at or below `edge0`, and `1` at or above `edge1`.
+            #[inline]
+            pub fn smoothstep(self, edge0: $VectorN<S>, edge1: $VectorN<S>) -> $VectorN<S> {
+                let two = S::one() + S::one();
+                let three = two + S::one();
+                $VectorN::new($({
+                    let t = ((self.$field - edge0.$field) / (edge1.$field - edge0.$field))
+                        .max(S::zero())
+                        .min(S::one());
+                    t * t * (three - two * t)
+                }),+)
+            }
+
+            /// Compare the vector with `other` lexicographically by component,
+            /// for sorting vectors of floats, where `Ord` is unavailable.
+            ///
+            /// This agrees with `PartialOrd`, which also compares vectors
+            /// lexicographically, except that `NaN` components are ordered
+            /// after all other values, and are equal to each other.
+            pub fn cmp_lex(&self, other: &$VectorN<S>) -> Ordering {
+                $(
+                    let ordering = match (self.$field.is_nan(), other.$field.is_nan()) {
+                        (false, false) => self.$field.partial_cmp(&other.$field).unwrap(),
+                        (lhs, rhs) => lhs.cmp(&rhs),
+                    };
+                    if ordering != Ordering::Equal {
+                        return ordering;
+                    }
+                )+
+                Ordering::Equal
+            }
+
+            /// Whether each component is within `epsilon` of the corresponding
+            /// component of `other`, using `approx::AbsDiffEq`.
+            #[inline]
+            pub fn abs_diff_eq_element_wise(self, other: $VectorN<S>, epsilon: S) -> $VectorN<bool> {
+                self.zip(other, |a, b| a.abs_diff_eq(&b, epsilon))
+            }
+
+            /// The point halfway between the vector and `other`.
+            #[inline]
+            pub fn midpoint(self, other: $VectorN<S>) -> $VectorN<S> {
+                self + (other - self) / (S::one() + S::one())
+            }
+        }
+
+        impl<S: Clone> $VectorN<S> {
+            /// Construct a vector from a slice, returning `None` if the length
+            /// of the slice does not match the number of components.
+            #[inline]
+            pub fn from_slice(slice: &[S]) -> Option<$VectorN<S>> {
+                match slice {
+                    [$($field),+] => Some($VectorN::new($($field.clone()),+)),
+                    _ => None,
+                }
+            }
+        }
+
         impl<S: NumCast + Copy> $VectorN<S> {
             /// Component-wise casting to another type.
             #[inline]
@@ -139,6 +376,49 @@ macro_rules! impl_vector {
             }
         }
 
+        /// Formats the vector as a tuple such as `(1, 2, 3)`. Formatting
+        /// options such as precision are applied to each component.
+        impl<S: fmt::Display> fmt::Display for $VectorN<S> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let components: &[S; $n] = self.as_ref();
+                f.write_str("(")?;
+                for (i, component) in components.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    fmt::Display::fmt(component, f)?;
+                }
+                f.write_str(")")
+            }
+        }
+
+        impl<S: FromStr> FromStr for $VectorN<S> {
+            type Err = ParseVectorError;
+
+            /// Parses a vector in the format produced by the `Display` impl,
+            /// with optional whitespace around the components.
+            fn from_str(s: &str) -> Result<$VectorN<S>, ParseVectorError> {
+                let s = s.trim();
+                let inner = s
+                    .strip_prefix('(')
+                    .and_then(|s| s.strip_suffix(')'))
+                    .ok_or(ParseVectorError)?;
+                let mut components = inner.split(',');
+                $(
+                    let $field = components
+                        .next()
+                        .ok_or(ParseVectorError)?
+                        .trim()
+                        .parse()
+                        .map_err(|_| ParseVectorError)?;
+                )+
+                match components.next() {
+                    Some(_) => Err(ParseVectorError),
+                    None => Ok($VectorN::new($($field),+)),
+                }
+            }
+        }
+
         impl<S: BaseNum> MetricSpace for $VectorN<S> {
             type Metric = S;
 
@@ -375,6 +655,18 @@ impl_vector!(Vector2 { x, y }, 2, vec2);
 impl_vector!(Vector3 { x, y, z }, 3, vec3);
 impl_vector!(Vector4 { x, y, z, w }, 4, vec4);
 
+/// An error returned when parsing a vector from a string fails.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ParseVectorError;
+
+impl fmt::Display for ParseVectorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid vector literal")
+    }
+}
+
+impl error::Error for ParseVectorError {}
+
 impl_fixed_array_conversions!(Vector1<S> { x: 0 }, 1);
 impl_fixed_array_conversions!(Vector2<S> { x: 0, y: 1 }, 2);
 impl_fixed_array_conversions!(Vector3<S> { x: 0, y: 1, z: 2 }, 3);
@@ -414,6 +706,13 @@ impl<S: BaseNum> Vector2<S> {
         (self.x * other.y) - (self.y * other.x)
     }
 
+    /// The outer product of the vector and `other`, `self * other^T`, such
+    /// that `a.outer(b) * c` is equal to `a * b.dot(c)` for any `c`.
+    #[inline]
+    pub fn outer(self, other: Vector2<S>) -> Matrix2<S> {
+        Matrix2::from_cols(self * other.x, self * other.y)
+    }
+
     /// Create a `Vector3`, using the `x` and `y` values from this vector, and the
     /// provided `z`.
     #[inline]
@@ -453,6 +752,13 @@ impl<S: BaseNum> Vector3<S> {
         )
     }
 
+    /// The outer product of the vector and `other`, `self * other^T`, such
+    /// that `a.outer(b) * c` is equal to `a * b.dot(c)` for any `c`.
+    #[inline]
+    pub fn outer(self, other: Vector3<S>) -> Matrix3<S> {
+        Matrix3::from_cols(self * other.x, self * other.y, self * other.z)
+    }
+
     /// Create a `Vector4`, using the `x`, `y` and `z` values from this vector, and the
     /// provided `w`.
     #[inline]
@@ -469,6 +775,123 @@ impl<S: BaseNum> Vector3<S> {
     impl_swizzle_functions!(Vector1, Vector2, Vector3, Vector4, S, xyz);
 }
 
+impl<S: BaseFloat> Vector2<S> {
+    /// Construct a vector from polar coordinates, with `theta` measured
+    /// anticlockwise from the positive `x` axis.
+    ///
+    /// ```rust
+    /// use cgmath::{Deg, Vector2};
+    ///
+    /// let v = Vector2::from_polar(2.0, Deg(0.0));
+    /// assert_eq!(v, Vector2::new(2.0, 0.0));
+    /// ```
+    #[inline]
+    pub fn from_polar<A: Into<Rad<S>>>(radius: S, theta: A) -> Vector2<S> {
+        let (s, c) = Rad::sin_cos(theta.into());
+        Vector2::new(radius * c, radius * s)
+    }
+
+    /// Convert the vector to polar coordinates `(radius, theta)`, using the
+    /// same convention as `Vector2::from_polar`.
+    ///
+    /// `theta` is normalized to the range `[0, full_turn)`, and the zero vector
+    /// returns zero for both coordinates.
+    #[inline]
+    pub fn to_polar(self) -> (S, Rad<S>) {
+        let theta = Rad::atan2(self.y + S::zero(), self.x + S::zero());
+        (self.magnitude(), theta.normalize())
+    }
+}
+
+impl<S: BaseFloat> Vector3<S> {
+    /// Construct a vector from spherical coordinates, using the physics
+    /// convention: `theta` is the inclination from the positive `z` axis, and
+    /// `phi` is the azimuth in the `xy` plane, measured anticlockwise from the
+    /// positive `x` axis.
+    ///
+    /// ```rust
+    /// use cgmath::{Deg, Vector3};
+    ///
+    /// let v = Vector3::from_sphere(2.0, Deg(0.0), Deg(45.0));
+    /// assert_eq!(v, Vector3::new(0.0, 0.0, 2.0));
+    /// ```
+    #[inline]
+    pub fn from_sphere<A: Into<Rad<S>>>(radius: S, theta: A, phi: A) -> Vector3<S> {
+        let (sin_theta, cos_theta) = Rad::sin_cos(theta.into());
+        let (sin_phi, cos_phi) = Rad::sin_cos(phi.into());
+        Vector3::new(
+            radius * sin_theta * cos_phi,
+            radius * sin_theta * sin_phi,
+            radius * cos_theta,
+        )
+    }
+
+    /// Convert the vector to spherical coordinates `(radius, theta, phi)`,
+    /// using the same convention as `Vector3::from_sphere`.
+    ///
+    /// `theta` is in the range `[0, turn_div_2]` and `phi` is in the range
+    /// `(-turn_div_2, turn_div_2]`. The zero vector returns zero for all
+    /// three coordinates, and vectors on the `z` axis return a `phi` of zero.
+    #[inline]
+    pub fn to_sphere(self) -> (S, Rad<S>, Rad<S>) {
+        // Adding zero turns negative zeros positive, keeping the angles within
+        // their documented ranges.
+        let (x, y, z) = (self.x + S::zero(), self.y + S::zero(), self.z + S::zero());
+        let theta = Rad::atan2(x.hypot(y), z);
+        let phi = Rad::atan2(y, x);
+        (self.magnitude(), theta, phi)
+    }
+
+    /// Returns some vector perpendicular to this one, for example to start
+    /// building a tangent frame around a normal.
+    ///
+    /// This returns `(-y, x, 0)` when `|x| >= |z|`, and `(0, -z, y)`
+    /// otherwise. The component that is dropped is never larger than the
+    /// other of `x` and `z`, so the result is at least `1 / sqrt(2)` times the
+    /// length of the vector, even when it is nearly aligned with an axis. The
+    /// result is not normalized, and the zero vector returns zero.
+    #[inline]
+    pub fn any_perpendicular(self) -> Vector3<S> {
+        if self.x.abs() >= self.z.abs() {
+            Vector3::new(-self.y, self.x, S::zero())
+        } else {
+            Vector3::new(S::zero(), -self.z, self.y)
+        }
+    }
+
+    /// Returns two unit vectors `(tangent, bitangent)`, that are
+    /// perpendicular to each other and to this vector, forming a
+    /// right-handed frame with the normalized vector `n`, such that
+    /// `tangent.cross(bitangent) == n`.
+    ///
+    /// The vector should not be zero.
+    #[inline]
+    pub fn orthonormal_basis(self) -> (Vector3<S>, Vector3<S>) {
+        let tangent = self.any_perpendicular().normalize();
+        let bitangent = self.normalize().cross(tangent);
+        (tangent, bitangent)
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<S: BaseFloat> Vector3<S>
+where
+    Standard: Distribution<S>,
+{
+    /// Samples a vector uniformly from the surface of the unit sphere.
+    ///
+    /// Unlike normalizing a vector sampled from the unit cube, the resulting
+    /// directions are not clustered towards the corners of the cube.
+    pub fn rand_unit<R: Rng + ?Sized>(rng: &mut R) -> Vector3<S> {
+        let (u1, u2): (S, S) = (rng.gen(), rng.gen());
+        let two: S = NumCast::from(2).unwrap();
+        let z = u1 * two - S::one();
+        let r = (S::one() - z * z).sqrt();
+        let (sin, cos) = Rad::sin_cos(Rad::full_turn() * u2);
+        Vector3::new(r * cos, r * sin, z)
+    }
+}
+
 impl<S: BaseNum> Vector4<S> {
     /// A unit vector in the `x` direction.
     #[inline]
@@ -512,6 +935,16 @@ impl<S: BaseNum> Vector4<S> {
         }
     }
 
+    /// Create a `Vector3` from homogeneous coordinates, by dividing the `x`,
+    /// `y` and `z` values by `w`.
+    ///
+    /// A `w` of zero represents a direction rather than a position, and
+    /// results in infinite or `NaN` components for floating point vectors.
+    #[inline]
+    pub fn homogenize(self) -> Vector3<S> {
+        self.truncate() / self.w
+    }
+
     impl_swizzle_functions!(Vector1, Vector2, Vector3, Vector4, S, xyzw);
 }
 
@@ -524,6 +957,54 @@ where
     V::dot(a, b)
 }
 
+/// Whether any component of a boolean vector is `true`, like the GLSL `any`
+/// function.
+///
+/// ```rust
+/// use cgmath::{all, any, vec3};
+///
+/// let inside = vec3(0.5, 2.0, -0.5).lt_element_wise(vec3(1.0, 1.0, 1.0));
+/// assert!(any(inside));
+/// assert!(!all(inside));
+/// ```
+#[inline]
+pub fn any<V: Array<Element = bool>>(v: V) -> bool {
+    (0..V::len()).any(|i| v[i])
+}
+
+/// Whether every component of a boolean vector is `true`, like the GLSL `all`
+/// function.
+#[inline]
+pub fn all<V: Array<Element = bool>>(v: V) -> bool {
+    (0..V::len()).all(|i| v[i])
+}
+
+/// The average of all vectors in the slice, or `None` if it is empty.
+///
+/// This uses an incremental mean rather than summing all of the vectors
+/// first, so large slices of large vectors will not overflow.
+///
+/// ```rust
+/// use cgmath::{centroid, vec2};
+///
+/// let triangle = [vec2(1.0, 1.0), vec2(2.0, 3.0), vec2(3.0, 2.0)];
+/// assert_eq!(centroid(&triangle), Some(vec2(2.0, 2.0)));
+/// assert_eq!(centroid::<cgmath::Vector2<f64>>(&[]), None);
+/// ```
+pub fn centroid<V: VectorSpace>(points: &[V]) -> Option<V>
+where
+    V::Scalar: BaseFloat,
+{
+    let (first, rest) = points.split_first()?;
+    let mut count = V::Scalar::one();
+    let mut mean = *first;
+    for &point in rest {
+        count += V::Scalar::one();
+        mean = mean + (point - mean) / count;
+    }
+    Some(mean)
+}
+
 impl<S: BaseNum> InnerSpace for Vector1<S> {
     #[inline]
     fn dot(self, other: Vector1<S>) -> S {
@@ -542,7 +1023,12 @@ impl<S: BaseNum> InnerSpace for Vector2<S> {
     where
         S: BaseFloat,
     {
-        Rad::atan2(Self::perp_dot(self, other), Self::dot(self, other))
+        // Adding zero turns negative zeros positive, so that opposite vectors
+        // give `turn_div_2` rather than `-turn_div_2`, and zero length vectors
+        // give zero.
+        let perp_dot = Self::perp_dot(self, other) + S::zero();
+        let dot = Self::dot(self, other) + S::zero();
+        Rad::atan2(perp_dot, dot)
     }
 }
 
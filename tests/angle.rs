@@ -16,8 +16,27 @@
 #[macro_use]
 extern crate approx;
 extern crate cgmath;
+#[cfg(feature = "rand")]
+extern crate rand;
 
-use cgmath::{Angle, Deg, Rad};
+use cgmath::{Angle, Deg, Grad, Rad, Turn};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::f64;
+use std::hash::{Hash, Hasher};
+
+#[test]
+fn test_full_turn_div() {
+    assert_eq!(Deg::full_turn_div(8), Deg(45.0f64));
+    assert_eq!(Deg::full_turn_div(5), Deg(72.0f64));
+    assert_eq!(Deg::full_turn_div(12), Deg(30.0f64));
+    assert_eq!(Deg::full_turn_div(1), Deg::<f64>::full_turn());
+    assert_eq!(Grad::full_turn_div(4), Grad(100.0f64));
+    assert_ulps_eq!(Rad::full_turn_div(4), Rad(f64::consts::FRAC_PI_2));
+
+    assert_eq!(Rad::<f64>::full_turn_div(2), Rad::turn_div_2());
+    assert_eq!(Rad::<f64>::full_turn_div(6), Rad::turn_div_6());
+}
 
 #[test]
 fn test_normalize() {
@@ -31,6 +50,20 @@ fn test_normalize() {
     assert_ulps_eq!(&angle, &(Rad::full_turn() - Rad::turn_div_4()));
 }
 
+#[test]
+fn test_normalize_tiny_negative() {
+    assert_eq!(Rad(-1.0e-20f64).normalize(), Rad(0.0));
+    assert_eq!(Deg(-1.0e-14f64).normalize(), Deg(0.0));
+    assert!(Rad(-1.0e-9f64).normalize() < Rad::full_turn());
+
+    assert_eq!(Deg(720.0f64).normalize(), Deg(0.0));
+    assert_eq!(Deg(-720.0f64).normalize(), Deg(0.0));
+    assert_ulps_eq!((Rad::full_turn() * -3.0f64).normalize(), Rad(0.0));
+
+    assert_ulps_eq!(Deg(-7205.0f64).normalize(), Deg(355.0));
+    assert_ulps_eq!(Deg(-7205.0f64).normalize_signed(), Deg(-5.0));
+}
+
 #[test]
 fn test_normalize_signed() {
     let angle: Rad<f64> = Rad::full_turn().normalize_signed();
@@ -47,6 +80,162 @@ fn test_normalize_signed() {
 
     let angle: Rad<f64> = (-Rad::turn_div_2()).normalize_signed();
     assert_ulps_eq!(&angle, &Rad::turn_div_2());
+
+    assert_ulps_eq!(Deg(270.0f64).normalize_signed(), Deg(-90.0));
+    assert_ulps_eq!(Deg(-270.0f64).normalize_signed(), Deg(90.0));
+    assert_ulps_eq!(Deg(180.0f64).normalize_signed(), Deg(180.0));
+    assert_ulps_eq!(Deg(-180.0f64).normalize_signed(), Deg(180.0));
+    assert_ulps_eq!(Deg(540.0f64).normalize_signed(), Deg(180.0));
+}
+
+#[test]
+fn test_difference() {
+    assert_ulps_eq!(Deg(350.0f64).difference(Deg(10.0)), Deg(20.0));
+    assert_ulps_eq!(Deg(10.0f64).difference(Deg(350.0)), Deg(-20.0));
+    assert_ulps_eq!(Deg(-170.0f64).difference(Deg(170.0)), Deg(-20.0));
+    assert_ulps_eq!(Deg(170.0f64).difference(Deg(-170.0)), Deg(20.0));
+    assert_ulps_eq!(Deg(720.0f64).difference(Deg(30.0)), Deg(30.0));
+    assert_ulps_eq!(Deg(0.0f64).difference(Deg(180.0)), Deg(180.0));
+
+    let a = Rad(6.0f64);
+    let b = Rad(0.5f64);
+    assert_ulps_eq!((a + a.difference(b)).normalize(), b);
+}
+
+#[test]
+fn test_advance() {
+    assert_ulps_eq!(Deg(10.0f64).advance(Deg(90.0), 0.5), Deg(55.0));
+    assert_ulps_eq!(Deg(10.0f64).advance(Deg(-90.0), 0.5), Deg(325.0));
+    assert_ulps_eq!(Deg(90.0f64).advance(Deg(360.0), 3.5), Deg(270.0));
+    assert_ulps_eq!(Deg(90.0f64).advance(Deg(-360.0), 3.5), Deg(270.0));
+    assert_ulps_eq!(Rad(1.0f64).advance(Rad(2.0), 0.0), Rad(1.0));
+
+    for &dt in &[-10.0f64, -0.1, 0.0, 0.01, 1.0, 7.3, 1000.0] {
+        let angle = Rad(0.3f64).advance(Rad(-5.0), dt);
+        assert!(Rad(0.0) <= angle && angle < Rad::full_turn());
+    }
+}
+
+#[test]
+fn test_bisect() {
+    assert_ulps_eq!(Deg(10.0f64).bisect(Deg(30.0)), Deg(20.0));
+    assert_ulps_eq!(Deg(30.0f64).bisect(Deg(10.0)), Deg(20.0));
+    assert_ulps_eq!(Deg(350.0f64).bisect(Deg(30.0)), Deg(10.0));
+    assert_ulps_eq!(Deg(30.0f64).bisect(Deg(350.0)), Deg(10.0));
+    assert_ulps_eq!(Deg(-170.0f64).bisect(Deg(170.0)), Deg(180.0));
+    assert_ulps_eq!(Rad(0.5f64).bisect(Rad(0.5)), Rad(0.5));
+
+    // Opposite angles bisect anticlockwise from `self`
+    assert_ulps_eq!(Deg(0.0f64).bisect(Deg(180.0)), Deg(90.0));
+    assert_ulps_eq!(Deg(180.0f64).bisect(Deg(0.0)), Deg(270.0));
+    assert_ulps_eq!(Deg(45.0f64).bisect(Deg(225.0)), Deg(135.0));
+}
+
+#[test]
+fn test_lerp() {
+    assert_ulps_eq!(Deg(350.0f64).lerp(Deg(10.0), 0.5).normalize(), Deg(0.0));
+    assert_ulps_eq!(Deg(10.0f64).lerp(Deg(350.0), 0.5).normalize(), Deg(0.0));
+    assert_ulps_eq!(Deg(10.0f64).lerp(Deg(50.0), 0.25), Deg(20.0));
+
+    assert_eq!(Deg(350.0f64).lerp(Deg(10.0), 0.0), Deg(350.0));
+    assert_eq!(Deg(350.0f64).lerp(Deg(10.0), 1.0), Deg(10.0));
+    assert_eq!(Rad(0.3f64).lerp(Rad(6.1), 0.0), Rad(0.3));
+    assert_eq!(Rad(0.3f64).lerp(Rad(6.1), 1.0), Rad(6.1));
+}
+
+#[test]
+fn test_wrapped_comparison() {
+    use std::cmp::Ordering;
+
+    assert_eq!(Deg(-10.0f64).cmp_wrapped(Deg(350.0)), Some(Ordering::Equal));
+    assert_eq!(Deg(370.0f64).cmp_wrapped(Deg(20.0)), Some(Ordering::Less));
+    assert_eq!(Deg(f64::NAN).cmp_wrapped(Deg(20.0)), None);
+
+    assert_eq!(Deg(-10.0f64).min_wrapped(Deg(10.0)), Deg(10.0));
+    assert_eq!(Deg(-10.0f64).max_wrapped(Deg(10.0)), Deg(-10.0));
+    assert_eq!(Deg(-10.0f64).min_wrapped(Deg(350.0)), Deg(-10.0));
+    assert_eq!(Deg(-10.0f64).max_wrapped(Deg(350.0)), Deg(-10.0));
+
+    // The raw ordering is untouched
+    assert!(Deg(-10.0f64) < Deg(10.0));
+}
+
+#[test]
+fn test_abs_diff_eq_wrapped() {
+    assert!(Deg(359.9999f64).abs_diff_eq_wrapped(Deg(0.0001), 0.001));
+    assert!(Deg(0.0001f64).abs_diff_eq_wrapped(Deg(359.9999), 0.001));
+    assert!(!Deg(359.9f64).abs_diff_eq_wrapped(Deg(0.1), 0.001));
+    assert!(Deg(-10.0f64).abs_diff_eq_wrapped(Deg(710.0), 1.0e-9));
+    assert!(Deg(10.0f64).abs_diff_eq_wrapped(Deg(10.5), 0.5));
+    assert!(!Deg(10.0f64).abs_diff_eq_wrapped(Deg(10.5), 0.4));
+    assert!(Rad(1.0e-7f64).abs_diff_eq_wrapped(Rad::full_turn(), 1.0e-6));
+}
+
+#[test]
+fn test_sort_wrapped() {
+    let mut angles = vec![Deg(350.0f64), Deg(20.0), Deg(-10.0), Deg(380.0), Deg(710.0)];
+    angles.sort_by(|a, b| a.cmp_wrapped(*b).unwrap());
+    assert_eq!(
+        angles,
+        vec![Deg(20.0), Deg(380.0), Deg(350.0), Deg(-10.0), Deg(710.0)]
+    );
+}
+
+#[test]
+fn test_clamp() {
+    let (min, max) = (Deg(10.0f64), Deg(50.0f64));
+    assert_ulps_eq!(Deg(30.0f64).clamp(min, max), Deg(30.0));
+    assert_ulps_eq!(Deg(60.0f64).clamp(min, max), Deg(50.0));
+    assert_ulps_eq!(Deg(0.0f64).clamp(min, max), Deg(10.0));
+    assert_ulps_eq!(Deg(390.0f64).clamp(min, max), Deg(30.0));
+    assert_ulps_eq!(Deg(200.0f64).clamp(min, max), Deg(50.0));
+    assert_ulps_eq!(Deg(220.0f64).clamp(min, max), Deg(10.0));
+}
+
+#[test]
+fn test_clamp_wrapping_arc() {
+    let (min, max) = (Deg(330.0f64), Deg(30.0f64));
+    assert_ulps_eq!(Deg(0.0f64).clamp(min, max), Deg(0.0));
+    assert_ulps_eq!(Deg(20.0f64).clamp(min, max), Deg(20.0));
+    assert_ulps_eq!(Deg(-20.0f64).clamp(min, max), Deg(340.0));
+    assert_ulps_eq!(Deg(40.0f64).clamp(min, max), Deg(30.0));
+    assert_ulps_eq!(Deg(320.0f64).clamp(min, max), Deg(330.0));
+    assert_ulps_eq!(Deg(170.0f64).clamp(min, max), Deg(30.0));
+    assert_ulps_eq!(Deg(190.0f64).clamp(min, max), Deg(330.0));
+
+    assert_ulps_eq!(Deg(-40.0f64).clamp(Deg(-30.0), Deg(30.0)), Deg(330.0));
+}
+
+#[test]
+fn test_classification() {
+    assert!(!Deg(0.0f64).is_acute());
+    assert!(Deg(0.1f64).is_acute());
+    assert!(Deg(89.9f64).is_acute());
+    assert!(!Deg(90.0f64).is_acute());
+
+    assert!(Deg(90.0f64).is_right());
+    assert!(Rad::<f64>::turn_div_4().is_right());
+    assert!(Deg(450.0f64).is_right());
+    assert!(!Deg(89.9f64).is_right());
+    assert!(!Deg(90.1f64).is_right());
+    assert!(!Deg(-90.0f64).is_right());
+
+    assert!(!Deg(90.0f64).is_obtuse());
+    assert!(Deg(90.1f64).is_obtuse());
+    assert!(Deg(179.9f64).is_obtuse());
+    assert!(!Deg(180.0f64).is_obtuse());
+
+    assert!(Deg(180.0f64).is_straight());
+    assert!(Deg(-180.0f64).is_straight());
+    assert!(Rad::<f64>::turn_div_2().is_straight());
+    assert!(!Deg(179.9f64).is_straight());
+    assert!(!Deg(180.1f64).is_straight());
+
+    assert!(!Deg(180.0f64).is_reflex());
+    assert!(Deg(180.1f64).is_reflex());
+    assert!(Deg(359.9f64).is_reflex());
+    assert!(Deg(-90.0f64).is_reflex());
+    assert!(!Deg(360.0f64).is_reflex());
 }
 
 #[test]
@@ -68,6 +257,278 @@ fn test_conv() {
     assert_ulps_eq!(&angle, &Rad(30.0f64));
 }
 
+#[test]
+fn test_conv_grad() {
+    assert_ulps_eq!(Grad::from(Deg(90.0f64)), Grad(100.0));
+    assert_ulps_eq!(Deg::from(Grad(50.0f64)), Deg(45.0));
+    assert_ulps_eq!(Grad::from(Rad::turn_div_2()), Grad(200.0f64));
+    assert_ulps_eq!(Rad::from(Grad(100.0f64)), Rad::turn_div_4());
+
+    let angle: Rad<_> = Grad(-5.0f64).into();
+    let angle: Grad<_> = angle.into();
+    assert_ulps_eq!(&angle, &Grad(-5.0f64));
+
+    let angle: Deg<_> = Grad(30.0f64).into();
+    let angle: Grad<_> = angle.into();
+    assert_ulps_eq!(&angle, &Grad(30.0f64));
+
+    let angle: Grad<_> = Rad(30.0f64).into();
+    let angle: Rad<_> = angle.into();
+    assert_ulps_eq!(&angle, &Rad(30.0f64));
+
+    let angle: Grad<_> = Deg(-30.0f64).into();
+    let angle: Deg<_> = angle.into();
+    assert_ulps_eq!(&angle, &Deg(-30.0f64));
+}
+
+#[test]
+fn test_grad() {
+    assert_eq!(Grad::<f64>::full_turn(), Grad(400.0));
+    assert_ulps_eq!(Grad(500.0f64).normalize(), Grad(100.0));
+    assert_ulps_eq!(Grad(300.0f64).normalize_signed(), Grad(-100.0));
+    assert_ulps_eq!(Grad(100.0f64).sin(), 1.0);
+    assert_ulps_eq!(Grad::acos(-1.0f64), Grad(200.0));
+}
+
+#[test]
+fn test_dms() {
+    assert_ulps_eq!(Deg::from_dms(10.0f64, 30.0, 36.0), Deg(10.51));
+    assert_ulps_eq!(Deg::from_dms(-10.0f64, 30.0, 36.0), Deg(-10.51));
+    assert_ulps_eq!(Deg::from_dms(-0.0f64, 30.0, 0.0), Deg(-0.5));
+    assert_ulps_eq!(Deg::from_dms(0.0f64, 30.0, 0.0), Deg(0.5));
+
+    let (d, m, s) = Deg(-0.5f64).to_dms();
+    assert!(d == 0.0 && d.is_sign_negative());
+    assert_ulps_eq!(m, 30.0);
+    assert_ulps_eq!(s, 0.0);
+
+    let (d, m, s) = Deg(-10.51f64).to_dms();
+    assert_eq!((d, m), (-10.0, 30.0));
+    assert_relative_eq!(s, 36.0, epsilon = 1.0e-9);
+
+    for &angle in &[-359.99f64, -45.5, -0.01, 0.0, 12.345, 89.999] {
+        let (d, m, s) = Deg(angle).to_dms();
+        assert_relative_eq!(Deg::from_dms(d, m, s), Deg(angle), epsilon = 1.0e-12);
+    }
+}
+
+#[test]
+fn test_conv_turn() {
+    assert_ulps_eq!(Deg::from(Turn(0.25f64)), Deg(90.0));
+    assert_ulps_eq!(Turn::from(Deg(90.0f64)), Turn(0.25));
+    assert_ulps_eq!(Rad::from(Turn(0.5f64)), Rad::turn_div_2());
+    assert_ulps_eq!(Turn::from(Rad::turn_div_4()), Turn(0.25f64));
+    assert_ulps_eq!(Grad::from(Turn(0.75f64)), Grad(300.0));
+    assert_ulps_eq!(Turn::from(Grad(100.0f64)), Turn(0.25));
+
+    let angle: Rad<_> = Turn(-0.3f64).into();
+    let angle: Turn<_> = angle.into();
+    assert_ulps_eq!(&angle, &Turn(-0.3f64));
+
+    let angle: Deg<_> = Turn(1.7f64).into();
+    let angle: Turn<_> = angle.into();
+    assert_ulps_eq!(&angle, &Turn(1.7f64));
+}
+
+#[test]
+fn test_turn() {
+    assert_eq!(Turn::<f64>::full_turn(), Turn(1.0));
+    assert_eq!(Turn::<f64>::turn_div_4(), Turn(0.25));
+    assert_ulps_eq!(Turn(1.25f64).normalize(), Turn(0.25));
+    assert_ulps_eq!(Turn(-0.25f64).normalize(), Turn(0.75));
+    assert_ulps_eq!(Turn(-2.5f64).normalize(), Turn(0.5));
+    assert_ulps_eq!(Turn(0.75f64).normalize_signed(), Turn(-0.25));
+    assert_ulps_eq!(Turn(0.25f64).sin(), 1.0);
+    assert_eq!("0.5 turn".parse(), Ok(Turn(0.5f64)));
+    assert_eq!("2 turns".parse(), Ok(Turn(2.0f64)));
+}
+
+#[test]
+fn test_debug_precision() {
+    assert_eq!(format!("{:?}", Deg(45.0f64)), "45.0°");
+    assert_eq!(format!("{:?}", Rad(1.5f64)), "1.5 rad");
+
+    assert_eq!(format!("{:.2?}", Deg(0.1f64 + 0.2)), "0.30°");
+    assert_eq!(format!("{:.3?}", Rad(2.0f64 / 3.0)), "0.667 rad");
+    assert_eq!(format!("{:.1?}", Grad(0.25f64)), "0.2 grad");
+    assert_eq!(format!("{:.0?}", Deg(89.6f64)), "90°");
+    assert_eq!(format!("{:.0?}", Turn(0.4f64)), "0 turn");
+}
+
+#[test]
+fn test_from_str() {
+    assert_eq!("45°".parse(), Ok(Deg(45.0f64)));
+    assert_eq!("45 deg".parse(), Ok(Deg(45.0f64)));
+    assert_eq!(" -12.5 ".parse(), Ok(Deg(-12.5f64)));
+    assert_eq!("0.25 rad".parse(), Ok(Rad(0.25f64)));
+    assert_eq!("3".parse(), Ok(Rad(3.0f64)));
+    assert_eq!("50 grad".parse(), Ok(Grad(50.0f64)));
+
+    assert!("".parse::<Deg<f64>>().is_err());
+    assert!("deg".parse::<Deg<f64>>().is_err());
+    assert!("45 rad".parse::<Deg<f64>>().is_err());
+    assert!("1 grad".parse::<Rad<f64>>().is_err());
+    assert!("one rad".parse::<Rad<f64>>().is_err());
+}
+
+#[test]
+fn test_from_str_round_trip() {
+    for &angle in &[-720.5f64, -1.0, 0.0, 0.1, 45.0, 1.0e10] {
+        let deg = Deg(angle);
+        assert_ulps_eq!(format!("{:?}", deg).parse::<Deg<_>>().unwrap(), deg);
+        let rad = Rad(angle);
+        assert_ulps_eq!(format!("{:?}", rad).parse::<Rad<_>>().unwrap(), rad);
+        let grad = Grad(angle);
+        assert_ulps_eq!(format!("{:?}", grad).parse::<Grad<_>>().unwrap(), grad);
+        let turn = Turn(angle);
+        assert_ulps_eq!(format!("{:?}", turn).parse::<Turn<_>>().unwrap(), turn);
+    }
+}
+
+#[test]
+fn test_trig() {
+    assert_ulps_eq!(Deg(90.0f64).sin(), 1.0f64);
+    assert_ulps_eq!(Deg(180.0f64).cos(), -1.0f64);
+    assert_ulps_eq!(Deg(45.0f64).tan(), 1.0f64);
+
+    assert_ulps_eq!(Deg(30.0f64).sin(), Rad::from(Deg(30.0f64)).sin());
+    assert_ulps_eq!(Deg(30.0f64).cos(), Rad::from(Deg(30.0f64)).cos());
+    assert_ulps_eq!(Deg(30.0f64).tan(), Rad::from(Deg(30.0f64)).tan());
+}
+
+#[test]
+fn test_sin_cos() {
+    for &angle in &[-720.0f64, -135.0, -30.0, 0.0, 45.0, 90.0, 200.0, 359.0] {
+        let angle = Deg(angle);
+        let (s, c) = angle.sin_cos();
+        assert_ulps_eq!(s, angle.sin());
+        assert_ulps_eq!(c, angle.cos());
+
+        let angle = Rad::from(angle);
+        let (s, c) = angle.sin_cos();
+        assert_ulps_eq!(s, angle.sin());
+        assert_ulps_eq!(c, angle.cos());
+    }
+}
+
+#[test]
+fn test_reciprocal_trig() {
+    assert_ulps_eq!(Deg(30.0f64).csc(), 2.0);
+    assert_ulps_eq!(Deg(60.0f64).sec(), 2.0);
+    assert_ulps_eq!(Deg(45.0f64).cot(), 1.0);
+    assert_ulps_eq!(Rad(0.3f64).csc(), 1.0 / 0.3f64.sin());
+
+    // Poles produce infinities (or very large values) rather than panicking
+    assert_eq!(Rad(0.0f64).cot(), f64::INFINITY);
+    assert_eq!(Deg(-0.0f64).csc(), f64::NEG_INFINITY);
+    assert!(Deg(90.0f64).sec().abs() > 1.0e15);
+}
+
+#[test]
+fn test_inverse_trig() {
+    assert_ulps_eq!(Rad::asin(1.0f64), Rad::turn_div_4());
+    assert_ulps_eq!(Rad::acos(-1.0f64), Rad::turn_div_2());
+    assert_ulps_eq!(Rad::atan(1.0f64), Rad::turn_div_4() / 2.0);
+
+    assert_ulps_eq!(Deg::asin(0.5f64), Deg(30.0));
+    assert_ulps_eq!(Deg::acos(0.5f64), Deg(60.0));
+    assert_ulps_eq!(Deg::atan(-1.0f64), Deg(-45.0));
+}
+
+#[test]
+fn test_inverse_trig_out_of_domain() {
+    assert!(Rad::asin(1.5f64).0.is_nan());
+    assert!(Rad::acos(-1.5f64).0.is_nan());
+    assert!(Deg::asin(-2.0f64).0.is_nan());
+    assert!(Deg::acos(2.0f64).0.is_nan());
+    assert!(Rad::asin(f64::NAN).0.is_nan());
+}
+
+#[test]
+fn test_atan2() {
+    assert_ulps_eq!(Rad::atan2(1.0f64, 1.0), Rad(f64::consts::FRAC_PI_4));
+    assert_ulps_eq!(Rad::atan2(1.0f64, -1.0), Rad(3.0 * f64::consts::FRAC_PI_4));
+    assert_ulps_eq!(
+        Rad::atan2(-1.0f64, -1.0),
+        Rad(-3.0 * f64::consts::FRAC_PI_4)
+    );
+    assert_ulps_eq!(Rad::atan2(-1.0f64, 1.0), Rad(-f64::consts::FRAC_PI_4));
+
+    assert_ulps_eq!(Rad::atan2(1.0f64, 0.0), Rad::turn_div_4());
+    assert_ulps_eq!(Rad::atan2(-1.0f64, 0.0), -Rad::turn_div_4());
+
+    assert_ulps_eq!(Deg::atan2(1.0f64, 1.0), Deg(45.0));
+    assert_ulps_eq!(Deg::atan2(-1.0f64, -1.0), Deg(-135.0));
+    assert_ulps_eq!(Deg::atan2(1.0f64, 0.0), Deg(90.0));
+}
+
+#[test]
+fn test_hash() {
+    fn hash<T: Hash>(value: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    assert_eq!(hash(&Deg(90)), hash(&Deg(90)));
+    assert_eq!(hash(&Rad(3u32)), hash(&Rad(3u32)));
+
+    let set: HashSet<_> = [Deg(10), Deg(20), Deg(10)].iter().cloned().collect();
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(&Deg(20)));
+}
+
+#[test]
+fn test_hash_key() {
+    assert_eq!(Deg(90.0f64).hash_key(), Deg(450.0f64).hash_key());
+    assert_eq!(Deg(90.0f64).hash_key(), Deg(-270.0f64).hash_key());
+    assert_eq!(Grad(100.0f32).hash_key(), Grad(500.0f32).hash_key());
+    assert_eq!(Turn(0.25f64).hash_key(), Turn(-1.75f64).hash_key());
+    assert_eq!(Rad(0.0f64).hash_key(), Rad(-0.0f64).hash_key());
+    assert_eq!(
+        Rad(0.0f64).hash_key(),
+        Rad(2.0 * f64::consts::PI).hash_key()
+    );
+    assert_ne!(Deg(90.0f64).hash_key(), Deg(-90.0f64).hash_key());
+    assert_ne!(Deg(90.0f64).hash_key(), Deg(90.5f64).hash_key());
+    // The key is the bit pattern of the normalized value
+    assert_eq!(Deg(450.0f32).hash_key(), 90.0f64.to_bits());
+    assert_eq!(Rad(-0.0f32).hash_key(), 0);
+
+    let set: HashSet<_> = [Deg(10.0f64), Deg(370.0), Deg(20.0), Deg(-340.0)]
+        .iter()
+        .map(|angle| angle.hash_key())
+        .collect();
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(&Deg(-350.0f64).hash_key()));
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_rand() {
+    use rand::rngs::SmallRng;
+    use rand::{Rng, SeedableRng};
+
+    let mut rng = SmallRng::seed_from_u64(0x5eed);
+    let samples = 10_000;
+
+    let mut sum = 0.0f64;
+    for _ in 0..samples {
+        let angle: Deg<f64> = rng.gen();
+        assert!(Deg(0.0) <= angle && angle < Deg(360.0));
+        sum += angle.0;
+    }
+    assert_relative_eq!(sum / samples as f64, 180.0, epsilon = 5.0);
+
+    let mut sum = 0.0f64;
+    for _ in 0..samples {
+        let angle = Rad::<f64>::rand_signed(&mut rng);
+        assert!(-Rad::turn_div_2() < angle && angle <= Rad::turn_div_2());
+        sum += angle.0;
+    }
+    assert_relative_eq!(sum / samples as f64, 0.0, epsilon = 0.1);
+}
+
 mod rad {
     use cgmath::Rad;
 
@@ -99,3 +560,35 @@ mod deg {
         );
     }
 }
+
+mod grad {
+    use cgmath::Grad;
+
+    #[test]
+    fn test_iter_sum() {
+        assert_eq!(
+            Grad(2.0) + Grad(3.0) + Grad(4.0),
+            [Grad(2.0), Grad(3.0), Grad(4.0)].iter().sum()
+        );
+        assert_eq!(
+            Grad(2.0) + Grad(3.0) + Grad(4.0),
+            [Grad(2.0), Grad(3.0), Grad(4.0)].iter().cloned().sum()
+        );
+    }
+}
+
+mod turn {
+    use cgmath::Turn;
+
+    #[test]
+    fn test_iter_sum() {
+        assert_eq!(
+            Turn(0.25) + Turn(0.5) + Turn(1.0),
+            [Turn(0.25), Turn(0.5), Turn(1.0)].iter().sum()
+        );
+        assert_eq!(
+            Turn(0.25) + Turn(0.5) + Turn(1.0),
+            [Turn(0.25), Turn(0.5), Turn(1.0)].iter().cloned().sum()
+        );
+    }
+}
@@ -0,0 +1,120 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+fn rotation(degrees: f64) -> Quaternion<f64> {
+    Quaternion::from_axis_angle(Vector3::new(1.0, 2.0, -2.0).normalize(), Deg(degrees))
+}
+
+#[test]
+fn test_transform_point() {
+    let q = rotation(70.0);
+    let t = Vector3::new(1.0, -2.0, 3.0);
+    let dq = DualQuaternion::from_rotation_translation(q, t);
+    let p = Point3::new(4.0, 5.0, -6.0);
+
+    assert_ulps_eq!(dq.rotation(), q);
+    assert_ulps_eq!(dq.translation(), t);
+    assert_ulps_eq!(dq.transform_point(p), Point3::from_vec(q * p.to_vec() + t));
+    assert_ulps_eq!(dq.transform_vector(p.to_vec()), q * p.to_vec());
+
+    let m = Matrix4::from(dq);
+    assert_ulps_eq!(m.transform_point(p), dq.transform_point(p));
+}
+
+#[test]
+fn test_matrix_round_trip() {
+    let dq = DualQuaternion::from_rotation_translation(rotation(130.0), vec3(1.0, -2.0, 3.0));
+    let m = Matrix4::from(dq);
+    // The conversion may give `-dq`, which represents the same transformation
+    let converted = DualQuaternion::from(m);
+    assert_ulps_eq!(converted.rotation().angle_between(dq.rotation()), Rad(0.0));
+    assert_ulps_eq!(converted.translation(), dq.translation());
+    assert_ulps_eq!(Matrix4::from(DualQuaternion::from(m)), m);
+
+    let m = Matrix4::from_translation(vec3(1.0, -2.0, 3.0)) * Matrix4::from(rotation(-40.0));
+    assert_ulps_eq!(Matrix4::from(DualQuaternion::from(m)), m);
+}
+
+#[test]
+fn test_concat() {
+    let a = DualQuaternion::from_rotation_translation(rotation(30.0), vec3(1.0, -2.0, 3.0));
+    let b = DualQuaternion::from_rotation_translation(rotation(-100.0), vec3(0.5, 4.0, -1.0));
+    let p = Point3::new(4.0, 5.0, -6.0);
+
+    assert_relative_eq!(
+        (a * b).transform_point(p),
+        a.transform_point(b.transform_point(p)),
+        epsilon = 1.0e-12
+    );
+    assert_relative_eq!(
+        Matrix4::from(a * b),
+        Matrix4::from(a) * Matrix4::from(b),
+        epsilon = 1.0e-12
+    );
+    assert_ulps_eq!(a * DualQuaternion::one(), a);
+    assert_ulps_eq!(DualQuaternion::one() * a, a);
+}
+
+#[test]
+fn test_inverse_transform() {
+    let dq = DualQuaternion::from_rotation_translation(rotation(30.0), vec3(1.0, -2.0, 3.0));
+    let inverse = dq.inverse_transform().unwrap();
+    assert_relative_eq!(dq * inverse, DualQuaternion::one(), epsilon = 1.0e-12);
+
+    let zero = DualQuaternion::<f64>::new(Quaternion::zero(), Quaternion::zero());
+    assert!(zero.inverse_transform().is_none());
+}
+
+#[test]
+fn test_normalize() {
+    let dq = DualQuaternion::from_rotation_translation(rotation(30.0), vec3(1.0, -2.0, 3.0));
+    assert_ulps_eq!((dq * 3.0).normalize(), dq);
+    assert_ulps_eq!((dq * 3.0).normalize().real.magnitude(), 1.0);
+
+    let drifted = DualQuaternion::new(dq.real, dq.dual + dq.real * 0.01).normalize();
+    assert_ulps_eq!(drifted.real.dot(drifted.dual), 0.0);
+    assert_ulps_eq!(drifted.translation(), dq.translation());
+}
+
+#[test]
+fn test_nlerp_pure_rotation() {
+    let a = DualQuaternion::from_rotation(rotation(20.0));
+    let b = DualQuaternion::from_rotation(rotation(80.0));
+    let p = Point3::new(4.0, 5.0, -6.0);
+
+    // Rotations about the same axis blend to the same result as the matrix
+    let expected = Matrix4::from(rotation(50.0)).transform_point(p);
+    assert_ulps_eq!(a.nlerp(b, 0.5).transform_point(p), expected);
+    assert_ulps_eq!(a.nlerp(-b, 0.5).transform_point(p), expected);
+}
+
+#[test]
+fn test_nlerp_pure_translation() {
+    let a = DualQuaternion::from_translation(vec3(1.0, -2.0, 3.0));
+    let b = DualQuaternion::from_translation(vec3(-3.0, 4.0, 5.0));
+    let p = Point3::new(4.0, 5.0, -6.0);
+
+    let blended = Matrix4::from(a) * 0.25 + Matrix4::from(b) * 0.75;
+    assert_ulps_eq!(
+        a.nlerp(b, 0.75).transform_point(p),
+        blended.transform_point(p)
+    );
+    assert_ulps_eq!(a.nlerp(b, 0.75).translation(), vec3(-2.0, 2.5, 4.5));
+}
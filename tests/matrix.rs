@@ -15,6 +15,8 @@
 
 extern crate approx;
 extern crate cgmath;
+#[cfg(feature = "rand")]
+extern crate rand;
 
 pub mod matrix2 {
     use std::f64;
@@ -121,6 +123,15 @@ pub mod matrix2 {
         assert_eq!(A.determinant(), -2.0f64)
     }
 
+    #[test]
+    fn test_determinant_properties() {
+        assert_eq!(Matrix2::<f64>::identity().determinant(), 1.0);
+        assert_eq!(Matrix2::new(2.0f64, 0.0, 0.0, -3.0).determinant(), -6.0);
+        assert_ulps_eq!((A * B).determinant(), A.determinant() * B.determinant());
+        assert_ulps_eq!((B * C).determinant(), B.determinant() * C.determinant());
+        assert_ulps_eq!(A.transpose().determinant(), A.determinant());
+    }
+
     #[test]
     fn test_trace() {
         assert_eq!(A.trace(), 5.0f64);
@@ -134,6 +145,25 @@ pub mod matrix2 {
         );
     }
 
+    #[test]
+    fn test_transpose_product() {
+        assert_eq!(A.transpose().transpose(), A);
+        assert_eq!((A * B).transpose(), B.transpose() * A.transpose());
+        assert!((B * B.transpose()).is_symmetric());
+        assert!(!(A * B).is_symmetric());
+        assert_eq!(C.transpose(), C);
+    }
+
+    #[test]
+    fn test_from_rows() {
+        let m = Matrix2::from_rows(Vector2::new(1.0f64, 2.0), Vector2::new(3.0, 4.0));
+        assert_eq!(
+            m,
+            Matrix2::from_cols(Vector2::new(1.0f64, 2.0), Vector2::new(3.0, 4.0)).transpose()
+        );
+        assert_eq!(m, Matrix2::new(1.0, 3.0, 2.0, 4.0));
+    }
+
     #[test]
     fn test_transpose_self() {
         let mut mut_a = A;
@@ -154,6 +184,104 @@ pub mod matrix2 {
             .is_none());
     }
 
+    #[test]
+    fn test_invert_small_scale() {
+        // The determinant is below the default epsilon, but the matrix is
+        // perfectly well-conditioned
+        let m = Matrix2::from_cols(vec2(1.0e-4f32, 0.0), vec2(0.0, 1.0e-4));
+        assert!(m.is_invertible());
+        assert_ulps_eq!(
+            m.invert().unwrap(),
+            Matrix2::from_cols(vec2(1.0e4, 0.0), vec2(0.0, 1.0e4))
+        );
+
+        // A rank deficient matrix is singular at any scale
+        assert_eq!(
+            Matrix2::from_cols(vec2(1.0e-4f32, 2.0e-4), vec2(2.0e-4, 4.0e-4)).invert(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_solve_agrees_with_invert() {
+        let matrices = [
+            // Badly scaled, but each column is well-conditioned
+            Matrix2::from_diagonal(vec2(1.0e10f64, 1.0e-10)),
+            Matrix2::new(1.0e10, 0.0, 1.0, 1.0e-10),
+            B,
+            // Singular at any scale
+            Matrix2::new(1.0e10, 2.0e10, 1.0e-10, 2.0e-10),
+            Matrix2::zero(),
+        ];
+        let b = vec2(1.0, 2.0);
+        for m in matrices.iter() {
+            assert_eq!(m.solve(b).is_some(), m.invert().is_some(), "{:?}", m);
+        }
+
+        let m = matrices[0];
+        let x = m.solve(b).unwrap();
+        assert_approx_eq!(x, vec2(1.0e-10, 2.0e10));
+        assert_approx_eq!(x, m.invert().unwrap() * b);
+    }
+
+    #[test]
+    fn test_invert_round_trip() {
+        let matrices = [
+            B,
+            C,
+            Matrix2::from_angle(Deg(30.0)),
+            // Zeros on the diagonal, and a negative determinant
+            Matrix2::new(0.0, 1.0, 1.0, 0.0),
+            // Entries of very different magnitudes
+            Matrix2::new(1.0e3, 0.0, 2.0, 1.0e-3),
+        ];
+        for m in &matrices {
+            let inverse = m.invert().unwrap();
+            assert_approx_eq!(m * inverse, Matrix2::identity(), epsilon = 1.0e-12);
+            assert_approx_eq!(inverse * m, Matrix2::identity(), epsilon = 1.0e-12);
+        }
+
+        assert_eq!(Matrix2::<f64>::zero().invert(), None);
+    }
+
+    #[test]
+    fn test_assignment_operators() {
+        let mut m = A;
+        m += B;
+        assert_eq!(m, A + B);
+        m -= B;
+        assert_eq!(m, A);
+        m -= A;
+        assert_eq!(m, Matrix2::zero());
+
+        let mut m = A;
+        m *= B;
+        assert_eq!(m, A * B);
+        let mut m = A;
+        m *= Matrix2::identity();
+        assert_eq!(m, A);
+
+        let mut m = A;
+        m *= 2.5;
+        assert_eq!(m, A * 2.5);
+        m /= 2.5;
+        assert_eq!(m, A);
+    }
+
+    #[test]
+    fn test_display() {
+        let identity = Matrix2::<f64>::identity();
+        assert_eq!(format!("{}", identity), "[1, 0]\n[0, 1]");
+        assert_eq!(identity.to_string_flat(), "[[1, 0], [0, 1]]");
+
+        assert_eq!(format!("{}", A), "[1, 2]\n[3, 4]");
+        assert_eq!(A.to_string_flat(), "[[1, 2], [3, 4]]");
+        assert_eq!(
+            format!("{:.1}", Matrix2::new(-10.0f64, 0.25, 1.0, 2.0)),
+            "[-10.0, 1.0]\n[  0.2, 2.0]"
+        );
+    }
+
     #[test]
     fn test_predicates() {
         assert!(Matrix2::<f64>::identity().is_identity());
@@ -187,6 +315,12 @@ pub mod matrix2 {
         // Rotate the vector (1, 1) by π radians to the vector (-1, -1)
         let rot3: Matrix2<f64> = Matrix2::from_angle(Rad(f64::consts::PI));
         assert_ulps_eq!(rot3 * Vector2::new(1.0, 1.0), &Vector2::new(-1.0, -1.0));
+
+        // Rotating by degrees is equivalent, and the inverse is the transpose
+        let rot4 = Matrix2::from_angle(Deg(90.0f64));
+        assert_ulps_eq!(rot4, rot1);
+        assert_ulps_eq!(rot4.invert().unwrap(), rot4.transpose());
+        assert_ulps_eq!(rot4.determinant(), 1.0);
     }
 
     #[test]
@@ -389,6 +523,19 @@ pub mod matrix3 {
         assert_eq!(A.trace(), 15.0f64);
     }
 
+    #[test]
+    fn test_determinant_properties() {
+        assert_eq!(Matrix3::<f64>::identity().determinant(), 1.0);
+        assert_eq!(Matrix3::from_value(2.0f64).determinant(), 8.0);
+        let scale = Matrix3::from_diagonal(Vector3::new(2.0f64, -3.0, 0.5));
+        assert_eq!(scale.determinant(), -3.0);
+        assert_eq!(scale.trace(), -0.5);
+        assert_ulps_eq!((B * C).determinant(), B.determinant() * C.determinant());
+        assert_ulps_eq!((C * D).determinant(), C.determinant() * D.determinant());
+        assert_ulps_eq!((A * D).determinant(), 0.0);
+        assert_ulps_eq!(D.transpose().determinant(), D.determinant());
+    }
+
     #[test]
     fn test_transpose() {
         assert_eq!(
@@ -399,6 +546,34 @@ pub mod matrix3 {
         );
     }
 
+    #[test]
+    fn test_transpose_product() {
+        assert_eq!(A.transpose().transpose(), A);
+        assert_eq!((A * B).transpose(), B.transpose() * A.transpose());
+        assert!((B * B.transpose()).is_symmetric());
+        assert!(!(A * B).is_symmetric());
+        assert_eq!(D.transpose(), D);
+    }
+
+    #[test]
+    fn test_from_rows() {
+        let m = Matrix3::from_rows(
+            Vector3::new(1.0f64, 2.0, 3.0),
+            Vector3::new(4.0, 5.0, 6.0),
+            Vector3::new(7.0, 8.0, 9.0),
+        );
+        assert_eq!(
+            m,
+            Matrix3::from_cols(
+                Vector3::new(1.0f64, 2.0, 3.0),
+                Vector3::new(4.0, 5.0, 6.0),
+                Vector3::new(7.0, 8.0, 9.0)
+            )
+            .transpose()
+        );
+        assert_eq!(m, Matrix3::new(1.0, 4.0, 7.0, 2.0, 5.0, 8.0, 3.0, 6.0, 9.0));
+    }
+
     #[test]
     fn test_transpose_self() {
         let mut mut_a = A;
@@ -406,6 +581,305 @@ pub mod matrix3 {
         assert_eq!(mut_a, A.transpose());
     }
 
+    #[test]
+    fn test_rows_and_columns() {
+        let t = A.transpose();
+        for i in 0..3 {
+            assert_eq!(A.row(i), t[i]);
+            assert_eq!(A[i], t.row(i));
+        }
+
+        let mut m = A;
+        let src = Vector3::new(10.0, 11.0, 12.0);
+        assert_eq!(m.replace_row(1, src), A.row(1));
+        assert_eq!(m.row(1), src);
+        assert_eq!(m.row(0), A.row(0));
+        let col = m[2];
+        assert_eq!(m.replace_col(2, src), col);
+        assert_eq!(m[2], src);
+
+        let mut m = A;
+        m.swap_rows(0, 2);
+        assert_eq!(m.row(0), A.row(2));
+        assert_eq!(m.row(2), A.row(0));
+
+        let mut m = A;
+        m.swap_columns(0, 1);
+        assert_eq!(m[0], A[1]);
+        assert_eq!(m[1], A[0]);
+
+        // The transpose can be built from the rows
+        let mut m = Matrix3::zero();
+        for i in 0..3 {
+            m.replace_row(i, A[i]);
+        }
+        assert_eq!(m, t);
+    }
+
+    #[test]
+    fn test_rotation_vector() {
+        assert_eq!(
+            Matrix3::from_rotation_vector(Vector3::zero()),
+            Matrix3::<f64>::identity()
+        );
+        assert_eq!(
+            Matrix3::<f64>::identity().to_rotation_vector(),
+            Vector3::zero()
+        );
+
+        let axes = [
+            Vector3::unit_x(),
+            -Vector3::unit_y(),
+            Vector3::new(1.0f64, -2.0, 2.0) / 3.0,
+        ];
+        let angles = [0.1, 1.0, std::f64::consts::FRAC_PI_2, 2.5, 3.0];
+        for &axis in &axes {
+            for &angle in &angles {
+                let w = axis * angle;
+                let m = Matrix3::from_rotation_vector(w);
+                assert_approx_eq!(
+                    m,
+                    Matrix3::from_axis_angle(axis, Rad(angle)),
+                    epsilon = 1.0e-12
+                );
+                assert_approx_eq!(m.to_rotation_vector(), w, epsilon = 1.0e-9);
+            }
+        }
+
+        // Angles beyond a half turn come back as the equivalent rotation the
+        // other way around the axis
+        let axis = Vector3::new(0.0f64, 0.6, -0.8);
+        let found = Matrix3::from_rotation_vector(axis * 4.0).to_rotation_vector();
+        assert_approx_eq!(
+            found,
+            axis * (4.0 - 2.0 * std::f64::consts::PI),
+            epsilon = 1.0e-9
+        );
+    }
+
+    #[test]
+    fn test_rotation_vector_near_zero() {
+        for &magnitude in [1.0e-4, 1.0e-8, 1.0e-12, 1.0e-300].iter() {
+            let w = Vector3::new(1.0f64, -2.0, 2.0) / 3.0 * magnitude;
+            let m = Matrix3::from_rotation_vector(w);
+            assert!(m.is_finite());
+            assert_approx_eq!(m.to_rotation_vector(), w, max_relative = 1.0e-6);
+        }
+    }
+
+    #[test]
+    fn test_rotation_vector_near_pi() {
+        let axis = Vector3::new(1.0f64, -2.0, 2.0) / 3.0;
+        for &offset in [1.0e-3, 1.0e-6, 1.0e-9].iter() {
+            let w = axis * (std::f64::consts::PI - offset);
+            let m = Matrix3::from_rotation_vector(w);
+            assert_approx_eq!(m.to_rotation_vector(), w, epsilon = 1.0e-6);
+        }
+
+        // At exactly pi, either direction represents the same rotation
+        let w = axis * std::f64::consts::PI;
+        let found = Matrix3::from_rotation_vector(w).to_rotation_vector();
+        assert_approx_eq!(found.magnitude(), std::f64::consts::PI, epsilon = 1.0e-9);
+        assert_approx_eq!(
+            found.dot(axis).abs(),
+            std::f64::consts::PI,
+            epsilon = 1.0e-6
+        );
+        assert_approx_eq!(
+            Matrix3::from_rotation_vector(found),
+            Matrix3::from_rotation_vector(w),
+            epsilon = 1.0e-9
+        );
+    }
+
+    #[test]
+    fn test_from_cross() {
+        let w = Vector3::new(1.0f64, -2.0, 3.0);
+        let k = Matrix3::from_cross(w);
+        #[rustfmt::skip]
+        assert_eq!(k, Matrix3::new(
+            0.0, 3.0, 2.0,
+            -3.0, 0.0, 1.0,
+            -2.0, -1.0, 0.0,
+        ));
+        assert_eq!(k.transpose(), -k);
+        assert_eq!(k.determinant(), 0.0);
+
+        assert_eq!(
+            k * Vector3::new(4.0, 5.0, -6.0),
+            w.cross(Vector3::new(4.0, 5.0, -6.0))
+        );
+        assert_eq!(k * Vector3::unit_x(), w.cross(Vector3::unit_x()));
+        // Parallel vectors, including `w` itself, are in the null space
+        assert_eq!(k * w, Vector3::zero());
+        assert_eq!(k * (w * -2.5), Vector3::zero());
+
+        assert_eq!(Matrix3::from_cross(Vector3::<f64>::zero()), Matrix3::zero());
+    }
+
+    #[test]
+    fn test_from_outer() {
+        let a = Vector3::new(1.0f64, 2.0, 3.0);
+        let b = Vector3::new(4.0f64, 5.0, 6.0);
+        let m = Matrix3::from_outer(a, b);
+        assert_eq!(
+            m,
+            Matrix3::new(4.0, 8.0, 12.0, 5.0, 10.0, 15.0, 6.0, 12.0, 18.0)
+        );
+        assert_eq!(m.transpose(), Matrix3::from_outer(b, a));
+        assert_eq!(m.trace(), a.dot(b));
+
+        let v = Vector3::new(-2.0, 0.5, 1.0);
+        assert_eq!(m * v, a * b.dot(v));
+    }
+
+    #[test]
+    fn test_eigen_symmetric() {
+        #[rustfmt::skip]
+        let matrices = [
+            D,
+            // A covariance matrix
+            Matrix3::new(
+                4.0, 2.0, 0.6,
+                2.0, 3.0, 1.0,
+                0.6, 1.0, 2.0,
+            ),
+            // Negative and mixed sign eigenvalues of 1, -1 and -2
+            Matrix3::new(
+                0.0, 1.0, 0.0,
+                1.0, 0.0, 0.0,
+                0.0, 0.0, -2.0,
+            ),
+            // Singular
+            Matrix3::from_outer(Vector3::new(1.0, 2.0, 3.0), Vector3::new(1.0, 2.0, 3.0)),
+        ];
+        for &m in &matrices {
+            let (values, vectors) = m.eigen_symmetric();
+            assert!(values.x >= values.y && values.y >= values.z);
+            assert_approx_eq!(
+                vectors * vectors.transpose(),
+                Matrix3::identity(),
+                epsilon = 1.0e-12
+            );
+            assert_approx_eq!(vectors.determinant(), 1.0, epsilon = 1.0e-12);
+            assert_approx_eq!(
+                vectors * Matrix3::from_diagonal(values) * vectors.transpose(),
+                m,
+                epsilon = 1.0e-12
+            );
+            for c in 0..3 {
+                assert_approx_eq!(m * vectors[c], vectors[c] * values[c], epsilon = 1.0e-12);
+            }
+        }
+
+        assert_approx_eq!(
+            matrices[2].eigen_symmetric().0,
+            Vector3::new(1.0, -1.0, -2.0),
+            epsilon = 1.0e-12
+        );
+        assert_approx_eq!(
+            matrices[3].eigen_symmetric().0,
+            Vector3::new(14.0, 0.0, 0.0),
+            epsilon = 1.0e-12
+        );
+    }
+
+    #[test]
+    fn test_eigen_symmetric_repeated() {
+        // Two equal eigenvalues of 1 with eigenvectors in the plane
+        // perpendicular to the axis, and an eigenvalue of 4 along the axis
+        let axis = Vector3::new(1.0f64, 1.0, 1.0).normalize();
+        let m = Matrix3::identity() + Matrix3::from_outer(axis, axis) * 3.0;
+
+        let (values, vectors) = m.eigen_symmetric();
+        assert_approx_eq!(values, Vector3::new(4.0, 1.0, 1.0), epsilon = 1.0e-12);
+        assert_approx_eq!(vectors.x.dot(axis).abs(), 1.0, epsilon = 1.0e-12);
+        assert_approx_eq!(
+            vectors * vectors.transpose(),
+            Matrix3::identity(),
+            epsilon = 1.0e-12
+        );
+        assert_approx_eq!(
+            vectors * Matrix3::from_diagonal(values) * vectors.transpose(),
+            m,
+            epsilon = 1.0e-12
+        );
+
+        let (values, vectors) = Matrix3::from_value(2.0f64).eigen_symmetric();
+        assert_eq!(values, Vector3::new(2.0, 2.0, 2.0));
+        assert_eq!(vectors, Matrix3::identity());
+    }
+
+    #[test]
+    fn test_eigen_symmetric_diagonal() {
+        // Already diagonal, so no rotations are applied, and the values and
+        // vectors are exact
+        let m = Matrix3::from_diagonal(Vector3::new(1.0f64, 3.0, 2.0));
+        let (values, vectors) = m.eigen_symmetric();
+        assert_eq!(values, Vector3::new(3.0, 2.0, 1.0));
+        assert_eq!(
+            vectors,
+            Matrix3::from_cols(Vector3::unit_y(), Vector3::unit_z(), Vector3::unit_x())
+        );
+
+        // Off-diagonal entries below the relative tolerance are ignored
+        let mut nearly_diagonal = m;
+        nearly_diagonal[1][0] = 1.0e-20;
+        nearly_diagonal[0][1] = 1.0e-20;
+        assert_eq!(nearly_diagonal.eigen_symmetric(), (values, vectors));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn test_eigen_symmetric_not_symmetric() {
+        A.eigen_symmetric();
+    }
+
+    #[test]
+    fn test_orthonormalize() {
+        let rotation =
+            Matrix3::from_axis_angle(Vector3::new(1.0f64, 2.0, -2.0).normalize(), Deg(70.0));
+        let mut drifted = rotation;
+        for c in 0..3 {
+            for r in 0..3 {
+                drifted[c][r] += if (c + r) % 2 == 0 { 1.0e-3 } else { -1.0e-3 };
+            }
+        }
+        assert!(!relative_eq!(
+            drifted * drifted.transpose(),
+            Matrix3::identity(),
+            epsilon = 1.0e-4
+        ));
+
+        let m = drifted.orthonormalize();
+        assert_approx_eq!(m * m.transpose(), Matrix3::identity(), epsilon = 1.0e-12);
+        assert_approx_eq!(m.determinant(), 1.0, epsilon = 1.0e-12);
+        assert_approx_eq!(m, rotation, epsilon = 1.0e-2);
+
+        let mut m_self = drifted;
+        m_self.orthonormalize_self();
+        assert_eq!(m_self, m);
+
+        // Reflections keep their handedness
+        let reflection = Matrix3::from_diagonal(Vector3::new(2.0f64, 1.0, -3.0));
+        assert_ulps_eq!(reflection.orthonormalize().determinant(), -1.0);
+        assert_ulps_eq!(rotation.orthonormalize(), rotation);
+    }
+
+    #[test]
+    fn test_flat_array() {
+        let array: [f64; 9] = A.into();
+        assert_eq!(array, [1.0, 4.0, 7.0, 2.0, 5.0, 8.0, 3.0, 6.0, 9.0]);
+        assert_eq!(Matrix3::from(array), A);
+
+        let v = Vector3::new(1.0f64, 2.0, 3.0);
+        let slice = unsafe { std::slice::from_raw_parts(Array::as_ptr(&v), 3) };
+        assert_eq!(slice, &[1.0, 2.0, 3.0]);
+        let v_array: [f64; 3] = v.into();
+        assert_eq!(Vector3::from(v_array), v);
+    }
+
     #[test]
     fn test_invert() {
         assert!(Matrix3::<f64>::identity().invert().unwrap().is_identity());
@@ -418,6 +892,131 @@ pub mod matrix3 {
         );
     }
 
+    #[test]
+    fn test_invert_singular_with_rounding() {
+        // The determinant is not exactly zero due to rounding
+        let m = Matrix3::new(0.1f64, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9);
+        assert_ne!(m.determinant(), 0.0);
+        assert_eq!(m.invert(), None);
+    }
+
+    #[test]
+    fn test_invert_small_scale() {
+        let m = Matrix3::from_scale(0.004f32);
+        assert!(m.is_invertible());
+        assert_approx_eq!(
+            m.invert().unwrap(),
+            Matrix3::from_scale(250.0),
+            max_relative = 1e-6
+        );
+        assert_approx_eq!(m * m.invert().unwrap(), Matrix3::identity(), epsilon = 1e-6);
+
+        // A rank deficient matrix is singular at any scale
+        let col = Vector3::new(0.004f32, 0.002, 0.001);
+        assert_eq!(
+            Matrix3::from_cols(col, col * 2.0, col * -3.0).invert(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_invert_round_trip() {
+        let matrices = [
+            C,
+            D,
+            // Negative determinant
+            Matrix3::from_diagonal(Vector3::new(2.0, -0.5, 4.0)) * Matrix3::from_angle_y(Deg(30.0)),
+            // Zero on the diagonal
+            Matrix3::new(0.0, 1.0, 2.0, 3.0, 0.0, 1.0, 1.0, 1.0, 0.0),
+            // Ill-conditioned, but not singular
+            Matrix3::new(1.0, 1.0, 0.0, 1.0, 1.0 + 1.0e-6, 0.0, 0.0, 0.0, 1.0),
+        ];
+        for m in &matrices {
+            let inverse = m.invert().unwrap();
+            assert_approx_eq!(m * inverse, Matrix3::identity(), epsilon = 1.0e-9);
+            assert_approx_eq!(inverse * m, Matrix3::identity(), epsilon = 1.0e-9);
+        }
+
+        assert_eq!(Matrix3::<f64>::zero().invert(), None);
+    }
+
+    #[test]
+    fn test_solve() {
+        let x = Vector3::new(1.0f64, -2.0, 0.5);
+        assert_approx_eq!(C.solve(C * x).unwrap(), x, epsilon = 1.0e-12);
+        assert_approx_eq!(D.solve(D * x).unwrap(), x, epsilon = 1.0e-12);
+        assert_approx_eq!(
+            D.solve(x).unwrap(),
+            D.invert().unwrap() * x,
+            epsilon = 1.0e-12
+        );
+        assert_eq!(D.solve(Vector3::zero()), Some(Vector3::zero()));
+
+        // Requires pivoting, as the first diagonal element is zero
+        let m = Matrix3::new(0.0f64, 1.0, 2.0, 3.0, 0.0, 1.0, 1.0, 1.0, 0.0);
+        let x = Vector3::new(1.0, 2.0, 3.0);
+        assert_approx_eq!(m.solve(m * x).unwrap(), x, epsilon = 1.0e-12);
+
+        assert_eq!(A.solve(Vector3::new(1.0, 2.0, 3.0)), None);
+        assert_eq!(Matrix3::<f64>::zero().solve(Vector3::zero()), None);
+
+        // Badly scaled columns are accepted exactly when `invert` accepts them
+        let m = Matrix3::from_diagonal(vec3(1.0e10f64, 1.0, 1.0e-10));
+        assert!(m.invert().is_some());
+        assert_approx_eq!(m.solve(m * x).unwrap(), x);
+        let m = Matrix3::from_cols(
+            vec3(1.0e10f64, 0.0, 0.0),
+            vec3(0.0, 1.0e-10, 0.0),
+            vec3(0.0, 2.0e-10, 0.0),
+        );
+        assert_eq!(m.invert(), None);
+        assert_eq!(m.solve(x), None);
+    }
+
+    #[test]
+    fn test_assignment_operators() {
+        let mut m = A;
+        m += B;
+        assert_eq!(m, A + B);
+        m -= B;
+        assert_eq!(m, A);
+        m -= A;
+        assert_eq!(m, Matrix3::zero());
+
+        let mut m = A;
+        m *= B;
+        assert_eq!(m, A * B);
+        let mut m = A;
+        m *= Matrix3::identity();
+        assert_eq!(m, A);
+
+        let mut m = A;
+        m *= 2.5;
+        assert_eq!(m, A * 2.5);
+        m /= 2.5;
+        assert_eq!(m, A);
+    }
+
+    #[test]
+    fn test_display() {
+        let identity = Matrix3::<f64>::identity();
+        assert_eq!(format!("{}", identity), "[1, 0, 0]\n[0, 1, 0]\n[0, 0, 1]");
+        assert_eq!(
+            identity.to_string_flat(),
+            "[[1, 0, 0], [0, 1, 0], [0, 0, 1]]"
+        );
+
+        let m = Matrix3::new(1.0f64, -20.0, 3.0, 400.0, 5.0, 6.0, 7.0, 8.0, -0.5);
+        assert_eq!(
+            format!("{}", m),
+            "[  1, 400,    7]\n[-20,   5,    8]\n[  3,   6, -0.5]"
+        );
+        assert_eq!(
+            m.to_string_flat(),
+            "[[1, 400, 7], [-20, 5, 8], [3, 6, -0.5]]"
+        );
+    }
+
     #[test]
     fn test_predicates() {
         assert!(Matrix3::<f64>::identity().is_identity());
@@ -436,6 +1035,39 @@ pub mod matrix3 {
         assert!(D.is_invertible());
 
         assert!(Matrix3::from_value(6.0f64).is_diagonal());
+
+        let rotation =
+            Matrix3::from_axis_angle(Vector3::new(1.0f64, 2.0, 3.0).normalize(), Deg(50.0));
+        assert!(rotation.is_rotation());
+        assert!(Matrix3::<f64>::identity().is_rotation());
+        assert!(!rotation.is_symmetric());
+        assert!(!rotation.is_diagonal());
+
+        // Near misses
+        let mut nearly_identity = Matrix3::<f64>::identity();
+        nearly_identity[2][1] = 1.0e-3;
+        assert!(!nearly_identity.is_identity());
+        assert!(!nearly_identity.is_diagonal());
+        assert!(!nearly_identity.is_symmetric());
+
+        // Small but well-conditioned, with a determinant below f64 epsilon
+        let small = Matrix3::from_value(1.0e-6f64)
+            + Matrix3::from_diagonal(Vector3::new(1.0e-6, 2.0e-6, 3.0e-6));
+        assert!(small.determinant() < f64::EPSILON);
+        assert!(small.is_invertible());
+        assert_approx_eq!(
+            small * small.invert().unwrap(),
+            Matrix3::identity(),
+            epsilon = 1e-9
+        );
+
+        // Shear has a determinant of one, but is not a rotation
+        let shear = Matrix3::new(1.0f64, 0.0, 0.0, 0.5, 1.0, 0.0, 0.0, 0.0, 1.0);
+        assert_ulps_eq!(shear.determinant(), 1.0);
+        assert!(!shear.is_rotation());
+        // Reflections have orthonormal columns, but a determinant of minus one
+        assert!(!Matrix3::from_diagonal(Vector3::new(1.0f64, 1.0, -1.0)).is_rotation());
+        assert!(!Matrix3::from_scale(1.001f64).is_rotation());
     }
 
     #[test]
@@ -456,7 +1088,7 @@ pub mod matrix3 {
                 y: Rad(0.0),
                 z: Rad(0.0),
             });
-            assert_relative_eq!(found, expected, epsilon = 0.001);
+            assert_approx_eq!(found, expected, epsilon = 0.001);
         }
 
         #[test]
@@ -483,7 +1115,7 @@ pub mod matrix3 {
                 y: yaw,
                 z: Rad(0.0),
             });
-            assert_relative_eq!(found, expected, epsilon = 0.001);
+            assert_approx_eq!(found, expected, epsilon = 0.001);
         }
 
         #[test]
@@ -510,7 +1142,7 @@ pub mod matrix3 {
                 y: Rad(0.0),
                 z: roll,
             });
-            assert_relative_eq!(found, expected, epsilon = 0.001);
+            assert_approx_eq!(found, expected, epsilon = 0.001);
         }
 
         #[test]
@@ -538,7 +1170,7 @@ pub mod matrix3 {
                     y: Rad(0.0),
                     z: Rad(0.0),
                 });
-                assert_relative_eq!(found, expected, epsilon = 0.001);
+                assert_approx_eq!(found, expected, epsilon = 0.001);
             }
 
             #[test]
@@ -565,7 +1197,7 @@ pub mod matrix3 {
                     y: yaw,
                     z: Rad(0.0),
                 });
-                assert_relative_eq!(found, expected, epsilon = 0.001);
+                assert_approx_eq!(found, expected, epsilon = 0.001);
             }
 
             #[test]
@@ -592,7 +1224,7 @@ pub mod matrix3 {
                     y: Rad(0.0),
                     z: roll,
                 });
-                assert_relative_eq!(found, expected, epsilon = 0.001);
+                assert_approx_eq!(found, expected, epsilon = 0.001);
             }
 
             #[test]
@@ -608,6 +1240,45 @@ pub mod matrix3 {
                 check_from_axis_angle_z(Rad(-1.0));
             }
         }
+
+        mod arbitrary_axis {
+            use cgmath::*;
+
+            #[test]
+            fn test_matches_quaternion() {
+                let axes = [
+                    Vector3::new(1.0f64, -2.0, 2.0) / 3.0,
+                    Vector3::new(0.0, 0.6, -0.8),
+                    -Vector3::unit_z(),
+                ];
+                let angles = [Deg(0.0), Deg(-75.0), Deg(180.0), Deg(270.0), Deg(540.0)];
+                for &axis in &axes {
+                    for &angle in &angles {
+                        let found = Matrix3::from_axis_angle(axis, angle);
+                        let expected = Matrix3::from(Quaternion::from_axis_angle(axis, angle));
+                        assert_approx_eq!(found, expected, epsilon = 1.0e-12);
+                        assert_approx_eq!(found * axis, axis, epsilon = 1.0e-12);
+                    }
+                }
+            }
+
+            #[test]
+            fn test_principal_axes() {
+                let angle = Deg(37.0f64);
+                assert_ulps_eq!(
+                    Matrix3::from_axis_angle(Vector3::unit_x(), angle),
+                    Matrix3::from_angle_x(angle)
+                );
+                assert_ulps_eq!(
+                    Matrix3::from_axis_angle(Vector3::unit_y(), angle),
+                    Matrix3::from_angle_y(angle)
+                );
+                assert_ulps_eq!(
+                    Matrix3::from_axis_angle(Vector3::unit_z(), angle),
+                    Matrix3::from_angle_z(angle)
+                );
+            }
+        }
     }
 
     mod rotate_from_euler {
@@ -693,6 +1364,26 @@ pub mod matrix3 {
             assert_ulps_eq!(vec3(0.0, 1.0, 0.0), rot * vec);
         }
 
+        #[test]
+        fn test_basis() {
+            let (x, y, z) = (Vector3::unit_x(), Vector3::unit_y(), Vector3::unit_z());
+
+            let rot = Matrix3::from_angle_x(Deg(90.0f32));
+            assert_ulps_eq!(rot * x, x);
+            assert_ulps_eq!(rot * y, z);
+            assert_ulps_eq!(rot * z, -y);
+
+            let rot = Matrix3::from_angle_y(Deg(90.0f32));
+            assert_ulps_eq!(rot * x, -z);
+            assert_ulps_eq!(rot * y, y);
+            assert_ulps_eq!(rot * z, x);
+
+            let rot = Matrix3::from_angle_z(Rad::turn_div_4());
+            assert_ulps_eq!(rot * x, y);
+            assert_ulps_eq!(rot * y, -x);
+            assert_ulps_eq!(rot * z, z);
+        }
+
         #[test]
         fn test_xy() {
             let vec = vec3(0.0, 0.0, 1.0);
@@ -763,6 +1454,44 @@ pub mod matrix3 {
             ])
         );
     }
+
+    #[test]
+    fn test_look_to_short_dir() {
+        let dir = Vector3::new(1.0, 2.0, 3.0).normalize();
+        let up = Vector3::unit_y();
+
+        assert_ulps_eq!(
+            Matrix3::look_to_lh(dir * 1.0e-4f32, up),
+            Matrix3::look_to_lh(dir, up)
+        );
+        assert_ulps_eq!(
+            Matrix3::look_to_rh(dir * 1.0e-4f32, up),
+            Matrix3::look_to_rh(dir, up)
+        );
+    }
+
+    #[test]
+    fn test_look_to_degenerate() {
+        // A zero `dir` leaves the default view direction unchanged
+        let m = Matrix3::look_to_lh(Vector3::zero(), Vector3::unit_y());
+        assert_ulps_eq!(m, Matrix3::<f64>::identity());
+        let m = Matrix3::look_to_rh(Vector3::zero(), Vector3::unit_y());
+        assert_ulps_eq!(m, Matrix3::<f64>::identity());
+
+        // `up` is zero or parallel to `dir`
+        let dir = Vector3::new(0.0f64, 3.0, 0.0);
+        for &up in [Vector3::unit_y(), -Vector3::unit_y(), Vector3::zero()].iter() {
+            for m in [Matrix3::look_to_lh(dir, up), Matrix3::look_to_rh(dir, up)].iter() {
+                assert!(m.is_finite());
+                assert_ulps_eq!(m.transpose() * m, Matrix3::identity());
+                assert_approx_eq!(m.determinant(), 1.0, epsilon = 1.0e-12);
+            }
+            assert_ulps_eq!(
+                Matrix3::look_to_lh(dir, up) * Vector3::unit_y(),
+                Vector3::unit_z()
+            );
+        }
+    }
 }
 
 pub mod matrix4 {
@@ -1000,6 +1729,22 @@ pub mod matrix4 {
         assert_eq!(A.trace(), 34.0f64);
     }
 
+    #[test]
+    fn test_determinant_properties() {
+        assert_eq!(Matrix4::<f64>::identity().determinant(), 1.0);
+        let scale = Matrix4::from_nonuniform_scale(2.0f64, -3.0, 0.5);
+        assert_eq!(scale.determinant(), -3.0);
+        assert_eq!(scale.trace(), 0.5);
+        assert_eq!(
+            Matrix4::from_translation(Vector3::new(1.0f64, 2.0, 3.0)).determinant(),
+            1.0
+        );
+        assert_ulps_eq!((B * C).determinant(), B.determinant() * C.determinant());
+        assert_ulps_eq!((C * D).determinant(), C.determinant() * D.determinant());
+        assert_ulps_eq!((A * D).determinant(), 0.0);
+        assert_ulps_eq!(D.transpose().determinant(), D.determinant());
+    }
+
     #[test]
     fn test_transpose() {
         assert_eq!(
@@ -1011,6 +1756,24 @@ pub mod matrix4 {
         );
     }
 
+    #[test]
+    fn test_transpose_product() {
+        assert_eq!(A.transpose().transpose(), A);
+        assert_eq!((A * B).transpose(), B.transpose() * A.transpose());
+        assert!((B * B.transpose()).is_symmetric());
+        assert!(!(A * B).is_symmetric());
+        assert_eq!(D.transpose(), D);
+    }
+
+    #[test]
+    fn test_from_rows() {
+        let m = Matrix4::from_rows(V, V * 2.0, V * 3.0, V * 4.0);
+        assert_eq!(
+            m,
+            Matrix4::from_cols(V, V * 2.0, V * 3.0, V * 4.0).transpose()
+        );
+    }
+
     #[test]
     fn test_transpose_self() {
         let mut mut_a = A;
@@ -1018,6 +1781,87 @@ pub mod matrix4 {
         assert_eq!(mut_a, A.transpose());
     }
 
+    #[test]
+    fn test_index() {
+        let columns = [A.x, A.y, A.z, A.w];
+        for c in 0..4 {
+            assert_eq!(A[c], columns[c]);
+            for r in 0..4 {
+                assert_eq!(A[c][r], columns[c][r]);
+            }
+        }
+        assert_eq!(A[2][1], A.z.y);
+
+        let mut m = A;
+        m[3][0] = 100.0;
+        assert_eq!(m.w.x, 100.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_out_of_bounds() {
+        let i = 4;
+        let _ = A[i];
+    }
+
+    #[test]
+    fn test_flat_array() {
+        let m = Matrix4::new(
+            1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0,
+            16.0,
+        );
+        let array: [f32; 16] = m.into();
+        // Column-major, so each column is contiguous
+        assert_eq!(&array[0..4], &[m.x.x, m.x.y, m.x.z, m.x.w]);
+        assert_eq!(&array[12..16], &[m.w.x, m.w.y, m.w.z, m.w.w]);
+        assert_eq!(array[4 * 2 + 1], m[2][1]);
+        assert_eq!(Matrix4::from(array), m);
+
+        let as_ref: &[f32; 16] = m.as_ref();
+        assert_eq!(as_ref, &array);
+        let slice = unsafe { std::slice::from_raw_parts(Matrix::as_ptr(&m), 16) };
+        assert_eq!(slice, &array);
+
+        let nested: [[f32; 4]; 4] = m.into();
+        assert_eq!(nested[3], [13.0, 14.0, 15.0, 16.0]);
+        assert_eq!(Matrix4::from(nested), m);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let t = A.transpose();
+        for i in 0..4 {
+            assert_eq!(A.row(i), t[i]);
+            assert_eq!(A[i], t.row(i));
+        }
+
+        let mut m = A;
+        let src = Vector4::new(10.0, 11.0, 12.0, 13.0);
+        assert_eq!(m.replace_row(1, src), A.row(1));
+        assert_eq!(m.row(1), src);
+        assert_eq!(m.row(0), A.row(0));
+        let col = m[2];
+        assert_eq!(m.replace_col(2, src), col);
+        assert_eq!(m[2], src);
+
+        let mut m = A;
+        m.swap_rows(0, 2);
+        assert_eq!(m.row(0), A.row(2));
+        assert_eq!(m.row(2), A.row(0));
+
+        let mut m = A;
+        m.swap_columns(0, 1);
+        assert_eq!(m[0], A[1]);
+        assert_eq!(m[1], A[0]);
+
+        // The transpose can be built from the rows
+        let mut m = Matrix4::zero();
+        for i in 0..4 {
+            m.replace_row(i, A[i]);
+        }
+        assert_eq!(m, t);
+    }
+
     #[test]
     fn test_invert() {
         assert!(Matrix4::<f64>::identity().invert().unwrap().is_identity());
@@ -1111,6 +1955,160 @@ pub mod matrix4 {
         assert!((mat_f.invert().unwrap() * mat_f).is_identity());
     }
 
+    #[test]
+    fn test_invert_singular_with_rounding() {
+        let m = Matrix4::new(
+            0.1f64, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.0, 1.1, 1.2, 0.3, 0.1, 0.7, 0.2,
+        );
+        assert_eq!(m.invert(), None);
+    }
+
+    #[test]
+    fn test_invert_small_scale() {
+        let m = Matrix4::from_scale(0.001f32);
+        assert!(m.is_invertible());
+        assert_approx_eq!(
+            m.invert().unwrap(),
+            Matrix4::from_scale(1000.0),
+            max_relative = 1e-6
+        );
+        assert_approx_eq!(
+            m.invert_affine().unwrap(),
+            Matrix4::from_scale(1000.0),
+            max_relative = 1e-6
+        );
+
+        let (translation, rotation, scale) = m.decompose().unwrap();
+        assert_eq!(translation, Vector3::zero());
+        assert_ulps_eq!(rotation, Quaternion::one());
+        assert_approx_eq!(scale, Vector3::from_value(0.001), max_relative = 1e-6);
+    }
+
+    #[test]
+    fn test_invert_round_trip() {
+        let matrices = [
+            C,
+            D,
+            // Negative determinant
+            Matrix4::from_translation(Vector3::new(1.0, -2.0, 3.0))
+                * Matrix4::from_angle_x(Deg(30.0))
+                * Matrix4::from_nonuniform_scale(2.0, -0.5, 4.0),
+            // Not affine
+            perspective(Deg(60.0), 1.5, 0.1, 100.0),
+        ];
+        for m in &matrices {
+            let inverse = m.invert().unwrap();
+            assert_approx_eq!(m * inverse, Matrix4::identity(), epsilon = 1.0e-9);
+            assert_approx_eq!(inverse * m, Matrix4::identity(), epsilon = 1.0e-9);
+        }
+
+        assert_eq!(Matrix4::<f64>::zero().invert(), None);
+    }
+
+    #[test]
+    fn test_solve() {
+        let x = Vector4::new(1.0f64, -2.0, 0.5, 3.0);
+        // Requires pivoting, as every diagonal element is zero
+        #[rustfmt::skip]
+        let permuted = Matrix4::new(
+            0.0, 1.0, 0.0, 0.0,
+            2.0, 0.0, 0.0, 1.0,
+            0.0, 0.0, 0.0, 3.0,
+            1.0, 0.0, 4.0, 0.0,
+        );
+        for m in &[C, D, permuted, perspective(Deg(60.0), 1.5, 0.1, 100.0)] {
+            assert_approx_eq!(m.solve(m * x).unwrap(), x, epsilon = 1.0e-9);
+            assert_approx_eq!(
+                m.solve(x).unwrap(),
+                m.invert().unwrap() * x,
+                epsilon = 1.0e-9
+            );
+        }
+
+        assert_eq!(A.solve(Vector4::new(1.0, 2.0, 3.0, 4.0)), None);
+        assert_eq!(Matrix4::<f64>::zero().solve(Vector4::zero()), None);
+    }
+
+    #[test]
+    fn test_assignment_operators() {
+        let mut m = A;
+        m += B;
+        assert_eq!(m, A + B);
+        m -= B;
+        assert_eq!(m, A);
+        m -= A;
+        assert_eq!(m, Matrix4::zero());
+
+        let mut m = A;
+        m *= B;
+        assert_eq!(m, A * B);
+        let mut m = A;
+        m *= Matrix4::identity();
+        assert_eq!(m, A);
+
+        let mut m = A;
+        m *= 2.5;
+        assert_eq!(m, A * 2.5);
+        m /= 2.5;
+        assert_eq!(m, A);
+    }
+
+    #[test]
+    fn test_viewport() {
+        let viewport = Matrix4::viewport(0.0f64, 0.0, 800.0, 600.0, 0.0, 1.0);
+        assert_eq!(
+            viewport.transform_point(Point3::new(-1.0, -1.0, -1.0)),
+            Point3::new(0.0, 0.0, 0.0)
+        );
+        assert_eq!(
+            viewport.transform_point(Point3::new(1.0, 1.0, 1.0)),
+            Point3::new(800.0, 600.0, 1.0)
+        );
+        assert_eq!(
+            viewport.transform_point(Point3::new(-1.0, 1.0, 0.0)),
+            Point3::new(0.0, 600.0, 0.5)
+        );
+
+        // A viewport with its corner away from the origin, and a reversed
+        // depth range
+        let viewport = Matrix4::viewport(100.0f64, 50.0, 200.0, 100.0, 1.0, 0.0);
+        assert_eq!(
+            viewport.transform_point(Point3::new(-1.0, -1.0, -1.0)),
+            Point3::new(100.0, 50.0, 1.0)
+        );
+        assert_eq!(
+            viewport.transform_point(Point3::new(1.0, 1.0, 1.0)),
+            Point3::new(300.0, 150.0, 0.0)
+        );
+        assert_eq!(
+            viewport.transform_point(Point3::new(0.0, 0.0, 0.0)),
+            Point3::new(200.0, 100.0, 0.5)
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let identity = Matrix4::<f64>::identity();
+        assert_eq!(
+            format!("{}", identity),
+            "[1, 0, 0, 0]\n[0, 1, 0, 0]\n[0, 0, 1, 0]\n[0, 0, 0, 1]"
+        );
+        assert_eq!(
+            format!("{:.2}", identity),
+            "[1.00, 0.00, 0.00, 0.00]\n[0.00, 1.00, 0.00, 0.00]\n[0.00, 0.00, 1.00, 0.00]\n[0.00, 0.00, 0.00, 1.00]"
+        );
+        assert_eq!(
+            identity.to_string_flat(),
+            "[[1, 0, 0, 0], [0, 1, 0, 0], [0, 0, 1, 0], [0, 0, 0, 1]]"
+        );
+
+        let m = Matrix4::from_translation(Vector3::new(10.0f64, -2.0, 0.5));
+        assert_eq!(
+            format!("{}", m),
+            "[1, 0, 0,  10]\n[0, 1, 0,  -2]\n[0, 0, 1, 0.5]\n[0, 0, 0,   1]"
+        );
+    }
+
     #[test]
     fn test_predicates() {
         assert!(Matrix4::<f64>::identity().is_identity());
@@ -1129,6 +2127,252 @@ pub mod matrix4 {
         assert!(D.is_invertible());
 
         assert!(Matrix4::from_value(6.0f64).is_diagonal());
+
+        let rotation =
+            Matrix4::from_axis_angle(Vector3::new(1.0f64, 2.0, 3.0).normalize(), Deg(50.0));
+        assert!(rotation.is_rotation());
+        assert!(Matrix4::<f64>::identity().is_rotation());
+        assert!(!rotation.is_symmetric());
+        assert!(!rotation.is_diagonal());
+
+        // Near misses
+        let mut nearly_identity = Matrix4::<f64>::identity();
+        nearly_identity[3][0] = 1.0e-3;
+        assert!(!nearly_identity.is_identity());
+        assert!(!nearly_identity.is_diagonal());
+        assert!(!nearly_identity.is_symmetric());
+        let small = Matrix4::from_value(1.0e-6f64);
+        assert!(small.is_invertible());
+        assert_approx_eq!(
+            small * small.invert().unwrap(),
+            Matrix4::identity(),
+            epsilon = 1e-9
+        );
+
+        // Translations and shears have a determinant of one, but are not rotations
+        let translation = Matrix4::from_translation(Vector3::new(1.0f64, 2.0, 3.0));
+        assert_ulps_eq!(translation.determinant(), 1.0);
+        assert!(!translation.is_rotation());
+        let shear = Matrix4::from_shear(0.5f64, 0.0, 0.0, 0.0, 0.0, 0.0);
+        assert_ulps_eq!(shear.determinant(), 1.0);
+        assert!(!shear.is_rotation());
+        assert!(!(rotation * Matrix4::from_nonuniform_scale(1.0, 1.0, -1.0)).is_rotation());
+    }
+
+    #[test]
+    fn test_transform_point_and_vector() {
+        let point = Point3::new(1.0f64, -2.0, 3.0);
+        let vector = Vector3::new(1.0f64, -2.0, 3.0);
+
+        let identity = Matrix4::<f64>::identity();
+        assert_eq!(identity.transform_point(point), point);
+        assert_eq!(identity.transform_vector(vector), vector);
+        assert_eq!(identity * vector.extend(1.0), vector.extend(1.0));
+
+        // Points have an implicit `w` of one, and vectors an implicit `w` of zero
+        let translation = Matrix4::from_translation(Vector3::new(4.0, 5.0, 6.0));
+        assert_eq!(
+            translation.transform_point(point),
+            Point3::new(5.0, 3.0, 9.0)
+        );
+        assert_eq!(translation.transform_vector(vector), vector);
+        assert_eq!(
+            translation * point.to_homogeneous(),
+            Vector4::new(5.0, 3.0, 9.0, 1.0)
+        );
+        assert_eq!(translation * vector.extend(0.0), vector.extend(0.0));
+
+        let rotation = Matrix3::from_angle_z(Deg(90.0));
+        assert_ulps_eq!(rotation * vector, Vector3::new(2.0, 1.0, 3.0));
+        assert_ulps_eq!(
+            Matrix4::from(rotation).transform_vector(vector),
+            rotation * vector
+        );
+    }
+
+    #[test]
+    fn test_matrix3_conversions() {
+        let m = Matrix3::new(1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+        assert_eq!(Matrix3::from(Matrix4::from(m)), m);
+        assert_eq!(
+            Matrix3::from(A),
+            Matrix3::from_cols(A.x.truncate(), A.y.truncate(), A.z.truncate())
+        );
+
+        let v = Vector3::new(10.0, 11.0, 12.0);
+        let combined = Matrix4::from_matrix3_translation(m, v);
+        assert_eq!(combined, Matrix4::from_translation(v) * Matrix4::from(m));
+        assert_eq!(Matrix3::from(combined), m);
+        assert_eq!(combined.w, v.extend(1.0));
+    }
+
+    #[test]
+    fn test_invert_affine() {
+        let shear = Matrix3::new(1.0, 0.0, 0.0, 0.5, 1.0, 0.0, 0.0, 0.0, 1.0);
+        let matrices = [
+            Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0))
+                * Matrix4::from_angle_x(Deg(30.0))
+                * Matrix4::from_nonuniform_scale(2.0, 0.5, -1.0),
+            Matrix4::from_translation(Vector3::new(-4.0, 0.0, 1.0)) * Matrix4::from_scale(0.25),
+            Matrix4::from_matrix3_translation(shear, Vector3::new(0.0, -3.0, 0.5)),
+            Matrix4::identity(),
+        ];
+        for m in &matrices {
+            let inverse = m.invert_affine().unwrap();
+            assert_approx_eq!(inverse, m.invert().unwrap(), epsilon = 1.0e-12);
+            assert_approx_eq!(m * inverse, Matrix4::identity(), epsilon = 1.0e-12);
+        }
+
+        let singular = Matrix4::from_translation(Vector3::new(1.0f64, 2.0, 3.0))
+            * Matrix4::from_nonuniform_scale(1.0, 0.0, 1.0);
+        assert_eq!(singular.invert_affine(), None);
+    }
+
+    #[test]
+    fn test_invert_rigid() {
+        let axis = Vector3::new(1.0f64, -2.0, 2.0) / 3.0;
+        let matrices = [
+            Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0)),
+            Matrix4::from_angle_z(Deg(90.0)),
+            Matrix4::from_translation(Vector3::new(-1.0, 0.5, 4.0))
+                * Matrix4::from_axis_angle(axis, Deg(120.0)),
+            // A half turn, where the rotation is its own inverse
+            Matrix4::from_translation(Vector3::new(0.0, 0.0, -2.0))
+                * Matrix4::from_axis_angle(axis, Deg(180.0)),
+            Matrix4::identity(),
+        ];
+        for m in &matrices {
+            let inverse = m.invert_rigid();
+            assert_approx_eq!(inverse, m.invert().unwrap(), epsilon = 1.0e-12);
+            assert_approx_eq!(inverse, m.invert_affine().unwrap(), epsilon = 1.0e-12);
+            assert_approx_eq!(m * inverse, Matrix4::identity(), epsilon = 1.0e-12);
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn test_invert_rigid_scaled() {
+        Matrix4::from_scale(2.0f64).invert_rigid();
+    }
+
+    #[test]
+    fn test_decompose() {
+        let axis = Vector3::new(1.0f64, -2.0, 2.0) / 3.0;
+        let cases = [
+            (Vector3::zero(), Quaternion::one(), Vector3::from_value(1.0)),
+            (
+                Vector3::new(1.0, 2.0, 3.0),
+                Quaternion::from_angle_z(Deg(90.0)),
+                Vector3::from_value(0.5),
+            ),
+            (
+                Vector3::new(-4.0, 0.0, 1.5),
+                Quaternion::from_axis_angle(axis, Deg(-135.0)),
+                Vector3::new(2.0, 0.5, 3.0),
+            ),
+            // A reflection, which is recovered as a negative x scale
+            (
+                Vector3::new(0.0, -1.0, 0.0),
+                Quaternion::from_axis_angle(axis, Deg(40.0)),
+                Vector3::new(1.5, 2.0, -0.25),
+            ),
+        ];
+        for &(translation, rotation, scale) in &cases {
+            let m = Matrix4::from_translation(translation)
+                * Matrix4::from(rotation)
+                * Matrix4::from_nonuniform_scale(scale.x, scale.y, scale.z);
+
+            let (t, r, s) = m.decompose().unwrap();
+            assert_approx_eq!(t, translation, epsilon = 1.0e-12);
+            assert_approx_eq!(s.y, scale.y, epsilon = 1.0e-12);
+            assert_approx_eq!(s.x.abs(), scale.x.abs(), epsilon = 1.0e-12);
+            assert_eq!(s.x < 0.0, scale.x * scale.y * scale.z < 0.0);
+            assert_approx_eq!(
+                s.x * s.y * s.z,
+                scale.x * scale.y * scale.z,
+                epsilon = 1.0e-12
+            );
+            assert_approx_eq!(r.magnitude(), 1.0, epsilon = 1.0e-12);
+
+            let recomposed = Matrix4::from_translation(t)
+                * Matrix4::from(r)
+                * Matrix4::from_nonuniform_scale(s.x, s.y, s.z);
+            assert_approx_eq!(recomposed, m, epsilon = 1.0e-12);
+        }
+
+        assert_eq!(
+            Matrix4::from_nonuniform_scale(1.0f64, 0.0, 1.0).decompose(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_normal_matrix() {
+        let m = Matrix4::from_translation(Vector3::new(1.0f64, 2.0, 3.0))
+            * Matrix4::from_angle_y(Deg(30.0))
+            * Matrix4::from_nonuniform_scale(4.0, 0.5, 2.0);
+        let normal_matrix = m.normal_matrix().unwrap();
+
+        let normal = Vector3::new(1.0, 1.0, 0.0);
+        let tangents = [Vector3::new(1.0, -1.0, 0.0), Vector3::new(-2.0, 2.0, 3.0)];
+        for &tangent in tangents.iter() {
+            let transformed_tangent = m.transform_vector(tangent);
+            let transformed_normal = normal_matrix * normal;
+            assert_approx_eq!(
+                transformed_normal.dot(transformed_tangent),
+                0.0,
+                epsilon = 1.0e-12
+            );
+
+            // The upper 3 x 3 corner alone does not keep the normal perpendicular
+            let skewed_normal = m.transform_vector(normal);
+            assert!(skewed_normal.dot(transformed_tangent).abs() > 0.1);
+        }
+
+        // For rotations, the normal matrix is the rotation itself
+        let rotation = Matrix4::from_angle_z(Deg(40.0));
+        assert_ulps_eq!(
+            rotation.normal_matrix().unwrap(),
+            Matrix3::from_angle_z(Deg(40.0))
+        );
+
+        assert_eq!(
+            Matrix4::from_nonuniform_scale(1.0, 0.0, 1.0).normal_matrix(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_from_shear() {
+        let mat = Matrix4::from_shear(1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0);
+        let point = Point3::new(1.0, 10.0, 100.0);
+        assert_eq!(mat.transform_point(point), Point3::new(211.0, 413.0, 165.0));
+        assert_eq!(
+            Matrix4::from_shear(0.0, 0.0, 0.0, 0.0, 0.0, 0.0),
+            Matrix4::<f64>::identity()
+        );
+    }
+
+    #[test]
+    fn test_translation_scale_order() {
+        let translation = Matrix4::from_translation(Vector3::new(1.0f64, 2.0, 3.0));
+        let scale = Matrix4::from_nonuniform_scale(2.0, 3.0, 4.0);
+        let point = Point3::new(1.0, 1.0, 1.0);
+
+        // The matrix on the right is applied first
+        assert_eq!(
+            (translation * scale).transform_point(point),
+            Point3::new(3.0, 5.0, 7.0)
+        );
+        assert_eq!(
+            (scale * translation).transform_point(point),
+            Point3::new(4.0, 9.0, 16.0)
+        );
+        assert_eq!(
+            Matrix4::from_scale(2.0f64),
+            Matrix4::from_nonuniform_scale(2.0, 2.0, 2.0)
+        );
     }
 
     #[test]
@@ -1224,3 +2468,39 @@ pub mod matrix4 {
         }
     }
 }
+
+#[cfg(feature = "rand")]
+mod rand_matrix {
+    use cgmath::*;
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_rand_rotation() {
+        let mut rng = SmallRng::seed_from_u64(0x5eed);
+        let v = Vector3::new(0.0f64, 0.6, 0.8);
+        let mut sum = Vector3::zero();
+        for _ in 0..1000 {
+            let m = Matrix3::<f64>::rand_rotation(&mut rng);
+            assert_approx_eq!(m * m.transpose(), Matrix3::identity(), epsilon = 1.0e-12);
+            assert_approx_eq!(m.determinant(), 1.0, epsilon = 1.0e-12);
+            sum += m * v;
+        }
+        assert_approx_eq!(sum / 1000.0, Vector3::zero(), epsilon = 0.1);
+    }
+
+    #[test]
+    fn test_rand_affine() {
+        let mut rng = SmallRng::seed_from_u64(0x5eed);
+        for _ in 0..100 {
+            let m = Matrix4::<f64>::rand_affine(&mut rng);
+            assert_eq!(m.row(3), Vector4::unit_w());
+
+            let (translation, _, scale) = m.decompose().unwrap();
+            for i in 0..3 {
+                assert!(-1.0 <= translation[i] && translation[i] < 1.0);
+                assert!(0.5 - 1.0e-12 <= scale[i] && scale[i] < 2.0 + 1.0e-12);
+            }
+        }
+    }
+}
@@ -13,9 +13,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[macro_use]
+extern crate approx;
 extern crate cgmath;
 
-use cgmath::{ortho, Matrix4, Vector4};
+use cgmath::prelude::*;
+use cgmath::{
+    assert_approx_eq, frustum, ortho, perspective, perspective_infinite, perspective_reversed_z,
+    Deg, Matrix4, Point3, Rad, Transform, Vector3, Vector4,
+};
 
 #[test]
 fn test_ortho_scale() {
@@ -66,3 +72,175 @@ fn test_ortho_translate() {
     let orig = o * vec_orig;
     assert_eq!(orig, Vector4::new(1., 1., 1., 1.));
 }
+
+#[test]
+fn test_perspective_depth() {
+    let p: Matrix4<f64> = perspective(Deg(60.0), 1.5, 0.1, 100.0);
+
+    // Points on the near and far planes map to the ends of the clip cube
+    let near = p.transform_point(Point3::new(0.0, 0.0, -0.1));
+    assert_approx_eq!(near.z, -1.0, epsilon = 1.0e-12);
+    let far = p.transform_point(Point3::new(3.0, -2.0, -100.0));
+    assert_approx_eq!(far.z, 1.0, epsilon = 1.0e-12);
+
+    // The clip-space `w` is the distance in front of the camera
+    let clip = p * Vector4::new(0.0, 0.0, -5.0, 1.0);
+    assert_eq!(clip.w, 5.0);
+}
+
+#[test]
+fn test_perspective_fov() {
+    let fovy = Deg(60.0);
+    let p: Matrix4<f64> = perspective(fovy, 1.5, 0.1, 100.0);
+
+    // A point on the top edge of the frustum maps to the top of the clip cube
+    let half_fovy = Rad::from(fovy).0 / 2.0;
+    let top = p.transform_point(Point3::new(0.0, 10.0 * half_fovy.tan(), -10.0));
+    assert_approx_eq!(top.x, 0.0, epsilon = 1.0e-12);
+    assert_approx_eq!(top.y, 1.0, epsilon = 1.0e-12);
+
+    // The horizontal extent is scaled by the aspect ratio
+    let right = p.transform_point(Point3::new(15.0 * half_fovy.tan(), 0.0, -10.0));
+    assert_approx_eq!(right.x, 1.0, epsilon = 1.0e-12);
+
+    // Radians and degrees give the same matrix
+    assert_eq!(p, perspective(Rad::from(fovy), 1.5, 0.1, 100.0));
+}
+
+#[test]
+#[should_panic]
+fn test_perspective_negative_near() {
+    let _: Matrix4<f64> = perspective(Deg(60.0), 1.5, -0.1, 100.0);
+}
+
+#[test]
+fn test_frustum_asymmetric_corners() {
+    let (left, right, bottom, top, near, far) = (-0.3f64, 0.1, -0.05, 0.2, 0.1, 50.0);
+    let p = frustum(left, right, bottom, top, near, far);
+
+    for &(x, y) in [(left, bottom), (left, top), (right, bottom), (right, top)].iter() {
+        let clip_x = if x == left { -1.0 } else { 1.0 };
+        let clip_y = if y == bottom { -1.0 } else { 1.0 };
+
+        let corner = p.transform_point(Point3::new(x, y, -near));
+        assert_approx_eq!(corner, Point3::new(clip_x, clip_y, -1.0), epsilon = 1.0e-12);
+
+        // The far plane corners lie along the rays through the near plane corners
+        let scale = far / near;
+        let corner = p.transform_point(Point3::new(x * scale, y * scale, -far));
+        assert_approx_eq!(corner, Point3::new(clip_x, clip_y, 1.0), epsilon = 1.0e-12);
+    }
+}
+
+#[test]
+fn test_ortho_asymmetric_corners() {
+    let (left, right, bottom, top, near, far) = (-3.0f64, 1.0, -0.5, 2.0, 1.0, 10.0);
+    let o = ortho(left, right, bottom, top, near, far);
+
+    for &(x, y) in [(left, bottom), (left, top), (right, bottom), (right, top)].iter() {
+        let clip_x = if x == left { -1.0 } else { 1.0 };
+        let clip_y = if y == bottom { -1.0 } else { 1.0 };
+
+        let corner = o.transform_point(Point3::new(x, y, -near));
+        assert_approx_eq!(corner, Point3::new(clip_x, clip_y, -1.0), epsilon = 1.0e-12);
+        let corner = o.transform_point(Point3::new(x, y, -far));
+        assert_approx_eq!(corner, Point3::new(clip_x, clip_y, 1.0), epsilon = 1.0e-12);
+    }
+}
+
+#[test]
+fn test_perspective_infinite() {
+    let p: Matrix4<f64> = perspective_infinite(Deg(60.0), 1.5, 0.1);
+
+    let near = p.transform_point(Point3::new(0.0, 0.0, -0.1));
+    assert_approx_eq!(near.z, -1.0, epsilon = 1.0e-12);
+
+    // Depth increases monotonically towards one
+    let mut previous = near.z;
+    for &distance in [0.2, 1.0, 10.0, 1.0e3, 1.0e6].iter() {
+        let depth = p.transform_point(Point3::new(0.0, 0.0, -distance)).z;
+        assert!(depth > previous && depth < 1.0);
+        previous = depth;
+    }
+
+    // The x and y scaling matches the finite projection
+    let finite: Matrix4<f64> = perspective(Deg(60.0), 1.5, 0.1, 100.0);
+    let point = Point3::new(1.0, -2.0, -30.0);
+    assert_ulps_eq!(p.transform_point(point).x, finite.transform_point(point).x);
+    assert_ulps_eq!(p.transform_point(point).y, finite.transform_point(point).y);
+}
+
+#[test]
+fn test_perspective_reversed_z() {
+    let p: Matrix4<f64> = perspective_reversed_z(Deg(60.0), 1.5, 0.1, 100.0);
+
+    let near = p.transform_point(Point3::new(0.0, 0.0, -0.1));
+    assert_approx_eq!(near.z, 1.0, epsilon = 1.0e-12);
+    let far = p.transform_point(Point3::new(0.0, 0.0, -100.0));
+    assert_approx_eq!(far.z, 0.0, epsilon = 1.0e-12);
+
+    // Depth decreases monotonically with distance
+    let mut previous = near.z;
+    for &distance in [0.2, 1.0, 10.0, 50.0, 99.0].iter() {
+        let depth = p.transform_point(Point3::new(0.0, 0.0, -distance)).z;
+        assert!(depth < previous && depth > 0.0);
+        previous = depth;
+    }
+}
+
+#[test]
+fn test_frustum_planes() {
+    let projection: Matrix4<f64> = perspective(Deg(90.0), 1.0, 1.0, 100.0);
+    let view = Matrix4::look_at_rh(
+        Point3::new(0.0, 0.0, 5.0),
+        Point3::new(0.0, 0.0, 0.0),
+        Vector3::unit_y(),
+    );
+    let planes = (projection * view).to_frustum_planes();
+    let distances = |point: Point3<f64>| {
+        let mut distances = [0.0; 6];
+        for (distance, plane) in distances.iter_mut().zip(planes.iter()) {
+            *distance = plane.truncate().dot(point.to_vec()) + plane.w;
+        }
+        distances
+    };
+
+    for plane in planes.iter() {
+        assert_ulps_eq!(plane.truncate().magnitude(), 1.0);
+    }
+
+    let inside = [
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(2.0, 2.0, 0.0),
+        Point3::new(-2.0, -2.0, 0.0),
+        Point3::new(0.0, 0.0, -90.0),
+        Point3::new(50.0, -50.0, -90.0),
+    ];
+    for &point in inside.iter() {
+        assert!(distances(point).iter().all(|&d| d > 0.0), "{:?}", point);
+    }
+
+    let outside = [
+        Point3::new(0.0, 0.0, 4.5),
+        Point3::new(0.0, 0.0, 10.0),
+        Point3::new(0.0, 0.0, -100.0),
+        Point3::new(6.0, 0.0, 0.0),
+        Point3::new(0.0, -6.0, 0.0),
+    ];
+    for &point in outside.iter() {
+        assert!(distances(point).iter().any(|&d| d < 0.0), "{:?}", point);
+    }
+
+    // Left, right, bottom, top, near, far
+    let d = distances(Point3::new(0.0, 0.0, 0.0));
+    let side = 5.0 / 2.0f64.sqrt();
+    let expected = [side, side, side, side, 4.0, 95.0];
+    for (&found, &expected) in d.iter().zip(expected.iter()) {
+        assert_approx_eq!(found, expected, epsilon = 1.0e-9);
+    }
+    assert_approx_eq!(
+        distances(Point3::new(6.0, 0.0, 0.0))[1],
+        -1.0 / 2.0f64.sqrt(),
+        epsilon = 1.0e-9
+    );
+}
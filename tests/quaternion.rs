@@ -15,6 +15,8 @@
 
 extern crate approx;
 extern crate cgmath;
+#[cfg(feature = "rand")]
+extern crate rand;
 
 macro_rules! impl_test_mul {
     ($s:expr, $v:expr) => {
@@ -213,9 +215,159 @@ mod to_from_euler {
             z: Rad(1f32),
         });
     }
+
+    fn check_gimbal_lock(rotation: Euler<Rad<f32>>) {
+        let quat = Quaternion::from(rotation);
+        let found = Euler::from(quat);
+        assert_eq!(found.x, Rad(0.0));
+        assert_relative_eq!(found.y, rotation.y, epsilon = 0.001);
+
+        let v = vec3(1.0, 2.0, 3.0);
+        assert_relative_eq!(Quaternion::from(found) * v, quat * v, epsilon = 0.001);
+    }
+
+    #[test]
+    fn test_gimbal_lock_pos_hp() {
+        check_gimbal_lock(Euler {
+            x: Rad(0.3f32),
+            y: Rad(HPI),
+            z: Rad(0.5f32),
+        });
+    }
+    #[test]
+    fn test_gimbal_lock_neg_hp() {
+        check_gimbal_lock(Euler {
+            x: Rad(0.3f32),
+            y: Rad(-HPI),
+            z: Rad(0.5f32),
+        });
+    }
+    #[test]
+    fn test_round_trip() {
+        for &x in &[-3.0f32, -1.2, 0.0, 0.4, 2.9] {
+            for &y in &[-1.5f32, -0.7, 0.0, 0.2, 1.5] {
+                for &z in &[-2.5f32, -0.1, 0.0, 1.1, 3.1] {
+                    check_euler(Euler {
+                        x: Rad(x),
+                        y: Rad(y),
+                        z: Rad(z),
+                    });
+                }
+            }
+        }
+    }
+}
+
+mod from_euler {
+    use cgmath::*;
+
+    fn check_components(x: Deg<f64>, y: Deg<f64>, z: Deg<f64>) {
+        let found = Quaternion::from(Euler { x, y, z });
+        let expected =
+            Quaternion::from_angle_x(x) * Quaternion::from_angle_y(y) * Quaternion::from_angle_z(z);
+        assert_ulps_eq!(found, expected);
+    }
+
+    #[test]
+    fn test_xyz_order() {
+        check_components(Deg(90.0), Deg(45.0), Deg(15.0));
+        check_components(Deg(-30.0), Deg(80.0), Deg(170.0));
+        check_components(Deg(10.0), Deg(-20.0), Deg(-135.0));
+    }
+
+    #[test]
+    fn test_from_euler_xyz() {
+        let angles = [
+            (Deg(90.0f64), Deg(45.0), Deg(15.0)),
+            (Deg(-30.0), Deg(80.0), Deg(170.0)),
+        ];
+        for &(x, y, z) in angles.iter() {
+            assert_eq!(
+                Quaternion::from_euler_xyz(Euler { x, y, z }),
+                Quaternion::from(Euler { x, y, z })
+            );
+        }
+    }
+
+    #[test]
+    fn test_zyx_order() {
+        let angles = [
+            (Deg(90.0f64), Deg(45.0), Deg(15.0)),
+            (Deg(-30.0), Deg(80.0), Deg(170.0)),
+            (Deg(10.0), Deg(-20.0), Deg(-135.0)),
+            (Deg(0.0), Deg(90.0), Deg(0.0)),
+        ];
+        for &(x, y, z) in angles.iter() {
+            let found = Quaternion::from_euler_zyx(Euler { x, y, z });
+            let expected = Quaternion::from_angle_z(z)
+                * Quaternion::from_angle_y(y)
+                * Quaternion::from_angle_x(x);
+            assert_ulps_eq!(found, expected);
+        }
+
+        // The order matters once more than one angle is non-zero
+        let euler = Euler::new(Deg(90.0f64), Deg(45.0), Deg(15.0));
+        let v = vec3(1.0, 2.0, 3.0);
+        assert!(Quaternion::from_euler_zyx(euler) * v != Quaternion::from(euler) * v);
+        // Angles given in radians give the same rotation
+        assert_ulps_eq!(
+            Quaternion::from_euler_zyx(Euler::new(Rad(0.3f64), Rad(-1.2), Rad(2.0))),
+            Quaternion::from_euler_zyx(Euler::new(
+                Deg::from(Rad(0.3f64)),
+                Deg::from(Rad(-1.2)),
+                Deg::from(Rad(2.0))
+            ))
+        );
+    }
 }
 
 mod from {
+    mod vector4 {
+        use cgmath::*;
+
+        #[test]
+        fn test_round_trip() {
+            let q = Quaternion::new(1.0f64, 2.0, 3.0, 4.0);
+            let v = Vector4::from(q);
+            assert_eq!(v, vec4(2.0, 3.0, 4.0, 1.0));
+            assert_eq!(Quaternion::from(v), q);
+
+            let array: [f64; 4] = q.into();
+            let v_array: [f64; 4] = v.into();
+            assert_eq!(array, v_array);
+
+            let slice = [0.0, 2.0, 3.0, 4.0, 1.0, 0.0];
+            let array: [f64; 4] = std::convert::TryFrom::try_from(&slice[1..5]).unwrap();
+            assert_eq!(Quaternion::from(array), q);
+        }
+
+        #[test]
+        fn test_tuple_slice_round_trip() {
+            let q = Quaternion::new(1.0f64, 2.0, 3.0, 4.0);
+            let tuple: (f64, f64, f64, f64) = q.into();
+            assert_eq!(tuple, (2.0, 3.0, 4.0, 1.0));
+            assert_eq!(Quaternion::from(tuple), q);
+
+            let array: [f64; 4] = q.into();
+            assert_eq!(Quaternion::from_slice(&array), Some(q));
+            assert_eq!(
+                Quaternion::from_slice(&[0.0, 2.0, 3.0, 4.0, 1.0][1..]),
+                Some(q)
+            );
+            assert_eq!(Quaternion::from_slice(&array[..3]), None);
+            assert_eq!(Quaternion::<f64>::from_slice(&[]), None);
+            assert_eq!(Quaternion::from_slice(&[1.0, 2.0, 3.0, 4.0, 5.0]), None);
+        }
+
+        #[test]
+        fn test_matrix3_ordering() {
+            let half_sqrt2 = 0.5f64.sqrt();
+            let q = Quaternion::from(vec4(0.0, 0.0, half_sqrt2, half_sqrt2));
+            assert_ulps_eq!(q, Quaternion::from_angle_z(Deg(90.0)));
+            assert_ulps_eq!(Matrix3::from(q), Matrix3::from_angle_z(Deg(90.0)));
+        }
+    }
+
     mod matrix3 {
         use cgmath::*;
 
@@ -249,6 +401,45 @@ mod from {
         fn test_zz_maximum() {
             check_with_euler(Rad(1.0f32), Rad(1.0), Rad(3.0f32));
         }
+
+        fn check_round_trip(quaternion: Quaternion<f64>) {
+            let found = Quaternion::from(Matrix3::from(quaternion));
+            // `q` and `-q` represent the same rotation
+            let found = if found.dot(quaternion) < 0.0 {
+                -found
+            } else {
+                found
+            };
+            assert_relative_eq!(found, quaternion, epsilon = 1.0e-12);
+        }
+
+        #[test]
+        fn test_round_trip() {
+            let axes = [
+                Vector3::unit_x(),
+                Vector3::unit_y(),
+                Vector3::unit_z(),
+                vec3(1.0, 1.0, 1.0).normalize(),
+                vec3(-0.3, 0.9, 0.1).normalize(),
+                vec3(0.5, -0.2, -0.8).normalize(),
+            ];
+            for &axis in &axes {
+                for i in -12..=12 {
+                    check_round_trip(Quaternion::from_axis_angle(axis, Deg(15.0 * i as f64)));
+                }
+            }
+        }
+
+        #[test]
+        fn test_half_turn() {
+            check_round_trip(Quaternion::from_angle_x(Deg(180.0)));
+            check_round_trip(Quaternion::from_angle_y(Deg(180.0)));
+            check_round_trip(Quaternion::from_angle_z(Deg(180.0)));
+            check_round_trip(Quaternion::from_axis_angle(
+                vec3(1.0, -1.0, 0.0).normalize(),
+                Deg(180.0),
+            ));
+        }
     }
 }
 
@@ -280,6 +471,29 @@ mod arc {
         test(vec3(1.0, 2.0, 3.0), vec3(-4.0, 5.0, -6.0));
     }
 
+    #[test]
+    fn test_opposite_arbitrary() {
+        let v = vec3(1.0, -2.0, 0.5);
+        test(v, -v);
+        test(Vector3::unit_y(), -Vector3::unit_y());
+        test(-Vector3::unit_z(), Vector3::unit_z());
+
+        let q = Quaternion::from_arc(v, -v, None);
+        assert!(q.is_finite());
+        assert_ulps_eq!(q.magnitude(), 1.0);
+    }
+
+    #[test]
+    fn test_nearly_opposite() {
+        let q = Quaternion::from_arc(Vector3::unit_x(), vec3(-1.0, 1.0e-3, 0.0), None);
+        assert!(q.is_finite());
+        assert_relative_eq!(
+            q.rotate_vector(Vector3::unit_x()),
+            vec3(-1.0, 1.0e-3, 0.0).normalize(),
+            epsilon = 1.0e-5
+        );
+    }
+
     #[test]
     fn test_ortho() {
         let q: Quaternion<f32> = Quaternion::from_arc(Vector3::unit_x(), Vector3::unit_y(), None);
@@ -288,6 +502,34 @@ mod arc {
     }
 }
 
+mod rotate_vector {
+    use cgmath::*;
+
+    #[test]
+    fn test_matches_matrix() {
+        let axes = [
+            vec3(1.0f64, 0.0, 0.0),
+            vec3(0.3, -0.4, 0.5).normalize(),
+            vec3(-2.0, 1.0, 7.0).normalize(),
+        ];
+        let vectors = [
+            vec3(1.0f64, 2.0, 3.0),
+            vec3(-5.0, 0.5, 0.0),
+            vec3(0.0, 0.0, -1.0),
+        ];
+        for &axis in &axes {
+            for i in -4..=8 {
+                let q = Quaternion::from_axis_angle(axis, Rad(0.7 * i as f64));
+                let m = Matrix3::from(q);
+                for &v in &vectors {
+                    assert_relative_eq!(q * v, m * v, epsilon = 1.0e-12);
+                    assert_relative_eq!(q.rotate_vector(v), m * v, epsilon = 1.0e-12);
+                }
+            }
+        }
+    }
+}
+
 mod rotate_from_euler {
     use cgmath::*;
 
@@ -370,6 +612,24 @@ mod rotate_from_axis_angle {
         assert_ulps_eq!(vec3(0.0, 1.0, 0.0), rot * vec);
     }
 
+    #[test]
+    fn test_unit_length() {
+        let axis = vec3(1.0f64, -2.0, 3.0).normalize();
+        for &angle in &[-720.0, -90.0, 0.0, 1.0e-6, 45.0, 180.0, 359.0, 1.0e6] {
+            let angle = Deg(angle);
+            assert_ulps_eq!(Quaternion::from_axis_angle(axis, angle).magnitude(), 1.0);
+            assert_ulps_eq!(Quaternion::from_angle_x(angle).magnitude(), 1.0);
+            assert_ulps_eq!(Quaternion::from_angle_y(angle).magnitude(), 1.0);
+            assert_ulps_eq!(Quaternion::from_angle_z(angle).magnitude(), 1.0);
+        }
+    }
+
+    #[test]
+    fn test_z_unit_x() {
+        let rot = Quaternion::from_axis_angle(Vector3::unit_z(), Rad::turn_div_4());
+        assert_ulps_eq!(rot * Vector3::<f64>::unit_x(), Vector3::unit_y());
+    }
+
     #[test]
     fn test_xy() {
         let vec = vec3(0.0, 0.0, 1.0);
@@ -404,6 +664,55 @@ mod rotate_from_axis_angle {
     }
 }
 
+mod to_axis_angle {
+    use cgmath::*;
+    use std::f64;
+
+    #[test]
+    fn test_identity() {
+        let (axis, angle) = Quaternion::<f64>::one().to_axis_angle();
+        assert_eq!(axis, Vector3::unit_x());
+        assert_eq!(angle, Rad(0.0));
+    }
+
+    #[test]
+    fn test_half_turn() {
+        let axis = vec3(0.0f64, 1.0, 1.0).normalize();
+        let (found_axis, angle) =
+            Quaternion::from_axis_angle(axis, Rad::turn_div_2()).to_axis_angle();
+        assert_ulps_eq!(found_axis, axis);
+        assert_ulps_eq!(angle, Rad::turn_div_2());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let axes = [
+            Vector3::unit_x(),
+            -Vector3::unit_z(),
+            vec3(1.0f64, -2.0, 3.0).normalize(),
+        ];
+        let pi = f64::consts::PI;
+        let angles = [
+            1.0e-3,
+            0.5,
+            1.0,
+            pi - 1.0e-4,
+            pi,
+            pi + 0.5,
+            6.0,
+            2.0 * pi - 1.0e-4,
+        ];
+        for &axis in &axes {
+            for &angle in &angles {
+                let q = Quaternion::from_axis_angle(axis, Rad(angle));
+                let (found_axis, found_angle) = q.to_axis_angle();
+                assert_relative_eq!(found_axis, axis, epsilon = 1.0e-9);
+                assert_relative_eq!(found_angle, Rad(angle), epsilon = 1.0e-9);
+            }
+        }
+    }
+}
+
 mod rotate_between_vectors {
     use cgmath::*;
 
@@ -469,3 +778,41 @@ mod cast {
         );
     }
 }
+
+#[cfg(feature = "rand")]
+mod rand_unit {
+    use cgmath::*;
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_unit_length() {
+        let mut rng = SmallRng::seed_from_u64(0x5eed);
+        for _ in 0..100 {
+            let q = Quaternion::<f64>::rand_unit(&mut rng);
+            assert_ulps_eq!(q.magnitude(), 1.0);
+        }
+    }
+
+    #[test]
+    fn test_unbiased() {
+        let mut rng = SmallRng::seed_from_u64(0x5eed);
+        let samples = 10_000;
+
+        let v = Vector3::new(0.0f64, 0.6, 0.8);
+        let mut sum = Vector3::zero();
+        let mut sum_s = 0.0;
+        for _ in 0..samples {
+            let q = Quaternion::<f64>::rand_unit(&mut rng);
+            sum += q * v;
+            sum_s += q.s.abs();
+        }
+        let mean = sum / samples as f64;
+        assert_relative_eq!(mean, Vector3::zero(), epsilon = 0.03);
+
+        // For uniformly distributed rotations, the mean of `|cos(angle / 2)|`
+        // is `4 / (3 * pi)`.
+        let expected = 4.0 / (3.0 * std::f64::consts::PI);
+        assert_relative_eq!(sum_s / samples as f64, expected, epsilon = 0.01);
+    }
+}
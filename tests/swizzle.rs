@@ -59,3 +59,21 @@ fn test_vector_swizzle() {
     assert_eq!(p3.yyx(), Vector3::new(2.0, 2.0, 1.0));
     assert_eq!(p4.xyxy(), Vector4::new(1.0, 2.0, 1.0, 2.0));
 }
+
+#[test]
+fn test_vector_swizzle_shader_style() {
+    let v3 = Vector3::new(1.0, 2.0, 3.0);
+    let v4 = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    assert_eq!(v3.xz(), Vector2::new(1.0, 3.0));
+    assert_eq!(v3.zyx(), Vector3::new(3.0, 2.0, 1.0));
+    assert_eq!(v3.xyz(), v3);
+    assert_eq!(v4.xy(), Vector2::new(1.0, 2.0));
+    assert_eq!(v4.xyz(), v3);
+    assert_eq!(v4.xyzw(), v4);
+    assert_eq!(v4.wzyx(), Vector4::new(4.0, 3.0, 2.0, 1.0));
+    assert_eq!(v4.zw(), Vector2::new(3.0, 4.0));
+
+    // Swizzles compose with the rest of the vector API
+    assert_eq!(v4.xyz().truncate(), v4.xy());
+    assert_eq!(v3.xy().extend(v3.z), v3);
+}
@@ -149,6 +149,113 @@ fn test_look_at_rh() {
     assert_ulps_eq!(&t.transform_point(point), &view_point);
 }
 
+#[test]
+fn test_look_at_rh_view() {
+    let eye = Point3::new(1.0f64, 2.0, 3.0);
+    let center = Point3::new(-2.0f64, 4.0, -3.0);
+    let up = Vector3::new(0.0f64, 1.0, 0.0);
+    let t = Matrix4::look_at_rh(eye, center, up);
+
+    let distance = (center - eye).magnitude();
+    assert_relative_eq!(t.transform_point(eye), Point3::origin(), epsilon = 1.0e-12);
+    assert_relative_eq!(
+        t.transform_point(center),
+        Point3::new(0.0, 0.0, -distance),
+        epsilon = 1.0e-12
+    );
+    // `up` is made perpendicular to the view direction
+    let view_up = t.transform_vector(up);
+    assert_relative_eq!(view_up.x, 0.0, epsilon = 1.0e-12);
+    assert!(view_up.y > 0.0);
+}
+
+#[test]
+fn test_look_at_rh_degenerate() {
+    let eye = Point3::new(1.0f64, 2.0, 3.0);
+
+    // `eye` is equal to `center`, so look along `-z`
+    let t = Matrix4::look_at_rh(eye, eye, Vector3::unit_y());
+    assert_ulps_eq!(t, Matrix4::from_translation(Point3::origin() - eye));
+
+    // `up` is parallel to the view direction
+    for &up in [Vector3::unit_y(), -Vector3::unit_y(), Vector3::zero()].iter() {
+        let center = eye + Vector3::new(0.0, 5.0, 0.0);
+        let t = Matrix4::look_at_rh(eye, center, up);
+        assert!(t.is_finite());
+        assert_eq!(t, Matrix4::look_at_rh(eye, center, up));
+        assert_relative_eq!(t.transform_point(eye), Point3::origin(), epsilon = 1.0e-12);
+        assert_relative_eq!(
+            t.transform_point(center),
+            Point3::new(0.0, 0.0, -5.0),
+            epsilon = 1.0e-12
+        );
+    }
+    let t = Matrix4::look_at_rh(eye, eye - Vector3::unit_z(), Vector3::unit_z());
+    assert!(t.is_finite());
+    assert_ulps_eq!(
+        t.transform_point(eye - Vector3::unit_z()),
+        Point3::new(0.0, 0.0, -1.0)
+    );
+}
+
+#[test]
+fn test_look_to_short_dir() {
+    let eye = Point3::new(1.0f32, 2.0, 3.0);
+    let dir = Vector3::new(0.6f32, 0.0, -0.8);
+    let up = Vector3::unit_y();
+    let short = dir * 1.0e-4;
+
+    assert_ulps_eq!(
+        Matrix4::look_to_rh(eye, short, up),
+        Matrix4::look_to_rh(eye, dir, up)
+    );
+    assert_ulps_eq!(
+        Matrix4::look_to_lh(eye, short, up),
+        Matrix4::look_to_lh(eye, dir, up)
+    );
+    // `eye + short` would round away most of `short`, so look from the origin
+    let origin = Point3::origin();
+    assert_ulps_eq!(
+        Matrix4::look_at_rh(origin, origin + short, up),
+        Matrix4::look_to_rh(origin, dir, up)
+    );
+    assert_ulps_eq!(
+        Matrix4::look_at_lh(origin, origin + short, up),
+        Matrix4::look_to_lh(origin, dir, up)
+    );
+    // A short `up` is not degenerate either
+    assert_ulps_eq!(
+        Matrix4::look_to_rh(eye, dir, up * 1.0e-4),
+        Matrix4::look_to_rh(eye, dir, up)
+    );
+}
+
+#[test]
+fn test_look_at_lh_degenerate() {
+    let eye = Point3::new(1.0f64, 2.0, 3.0);
+
+    // `eye` is equal to `center`, so look along `+z`
+    let t = Matrix4::look_at_lh(eye, eye, Vector3::unit_y());
+    assert!(t.is_finite());
+    assert_ulps_eq!(t.transform_point(eye), Point3::origin());
+    assert_ulps_eq!(
+        t.transform_point(eye + Vector3::unit_z()),
+        Point3::new(0.0, 0.0, 1.0)
+    );
+
+    // `up` is zero or parallel to the view direction
+    for &up in [Vector3::unit_y(), -Vector3::unit_y(), Vector3::zero()].iter() {
+        let center = eye + Vector3::new(0.0, 5.0, 0.0);
+        let t = Matrix4::look_at_lh(eye, center, up);
+        assert!(t.is_finite());
+        assert_relative_eq!(
+            t.transform_point(center),
+            Point3::new(0.0, 0.0, 5.0),
+            epsilon = 1.0e-12
+        );
+    }
+}
+
 #[cfg(feature = "serde")]
 #[test]
 fn test_serialize() {
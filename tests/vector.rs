@@ -15,9 +15,15 @@
 
 extern crate approx;
 extern crate cgmath;
+#[cfg(feature = "rand")]
+extern crate rand;
 
 use cgmath::*;
+use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeSet, HashSet};
 use std::f64;
+use std::hash::{Hash, Hasher};
 use std::iter;
 
 #[test]
@@ -47,6 +53,34 @@ fn test_from_value() {
     );
 }
 
+#[test]
+fn test_index() {
+    let v = Vector4::new(1.0f64, 2.0, 3.0, 4.0);
+    let fields = [v.x, v.y, v.z, v.w];
+    for i in 0..4 {
+        assert_eq!(v[i], fields[i]);
+    }
+    assert_eq!(&v[1..3], &[2.0, 3.0]);
+    assert_eq!(&v[..], &fields);
+
+    let mut v = Vector3::new(1, 2, 3);
+    for i in 0..3 {
+        v[i] *= 10;
+    }
+    assert_eq!(v, Vector3::new(10, 20, 30));
+
+    let v = Vector2::new(5, 6);
+    assert_eq!((v[0], v[1]), (v.x, v.y));
+}
+
+#[test]
+#[should_panic]
+fn test_index_out_of_bounds() {
+    let v = Vector3::new(1, 2, 3);
+    let i = 3;
+    let _ = v[i];
+}
+
 macro_rules! impl_test_add {
     ($VectorN:ident { $($field:ident),+ }, $s:expr, $v:expr) => (
         // vector + vector ops
@@ -165,6 +199,335 @@ fn test_sum() {
     assert_eq!(Vector4::new(5.0f64, 6.0f64, 7.0f64, 8.0f64).sum(), 26.0f64);
 }
 
+#[test]
+fn test_integer_vectors() {
+    let a = vec3(1i32, -2, 3);
+    let b = vec3(4i32, 5, -6);
+    assert_eq!(a + b, vec3(5, 3, -3));
+    assert_eq!(a * 3, vec3(3, -6, 9));
+    assert_eq!(a.dot(b), -24);
+    assert_eq!(a.cross(b), vec3(-3, 18, 13));
+    assert_eq!(a.sum(), 2);
+    assert_eq!(a.min_element_wise(b), vec3(1, -2, -6));
+    assert_eq!(a.abs().max_component(), 3);
+    assert_eq!(a.magnitude2(), 14);
+}
+
+#[test]
+fn test_checked_arithmetic() {
+    let max = vec3(i32::MAX, 0, 0);
+    let min = vec3(0, i32::MIN, 0);
+
+    assert_eq!(max.checked_add(vec3(0, 1, 2)), Some(vec3(i32::MAX, 1, 2)));
+    assert_eq!(max.checked_add(vec3(1, 0, 0)), None);
+    assert_eq!(
+        vec3(1, 2, 3).checked_add(vec3(4, 5, 6)),
+        Some(vec3(5, 7, 9))
+    );
+
+    assert_eq!(min.checked_sub(vec3(-1, 0, 0)), Some(vec3(1, i32::MIN, 0)));
+    assert_eq!(min.checked_sub(vec3(0, 1, 0)), None);
+    assert_eq!(vec2(5u8, 6).checked_sub(vec2(5, 7)), None);
+
+    assert_eq!(
+        vec3(i32::MAX / 2, -3, 4).checked_mul(2),
+        Some(vec3(i32::MAX - 1, -6, 8))
+    );
+    assert_eq!(max.checked_mul(2), None);
+    assert_eq!(min.checked_mul(-1), None);
+    assert_eq!(vec4(1u64, 2, 3, 4).checked_mul(0), Some(vec4(0, 0, 0, 0)));
+}
+
+#[test]
+fn test_outer() {
+    let (a, b) = (vec3(1.0f64, -2.0, 3.0), vec3(0.5f64, 4.0, -1.0));
+    for &c in &[vec3(2.0, 1.0, -3.0), b, Vector3::unit_z()] {
+        assert_eq!(a.outer(b) * c, a * b.dot(c));
+        let (a, b, c) = (a.truncate(), b.truncate(), c.truncate());
+        assert_eq!(a.outer(b) * c, a * b.dot(c));
+    }
+    assert_eq!(a.outer(b), Matrix3::from_outer(a, b));
+    assert_eq!(a.outer(b).transpose(), b.outer(a));
+    assert_eq!(a.outer(b).determinant(), 0.0);
+    assert_eq!(a.outer(Vector3::zero()), Matrix3::zero());
+    assert_eq!(
+        a.truncate().outer(b.truncate()).transpose(),
+        b.truncate().outer(a.truncate())
+    );
+
+    // Column-major: column `j` is `a * b[j]`.
+    let m = vec2(1, 2).outer(vec2(3, 4));
+    assert_eq!(m, Matrix2::new(3, 6, 4, 8));
+    assert_eq!(m.y, vec2(4, 8));
+}
+
+#[test]
+fn test_midpoint() {
+    let a = vec3(1.0f64, -2.0, 3.0);
+    let b = vec3(4.0f64, 5.0, -6.0);
+    assert_eq!(a.midpoint(b), vec3(2.5, 1.5, -1.5));
+    assert_eq!(a.midpoint(b), b.midpoint(a));
+    assert_eq!(a.midpoint(a), a);
+    assert_eq!(
+        vec2(f64::MAX, 0.0).midpoint(vec2(f64::MAX, 2.0)),
+        vec2(f64::MAX, 1.0)
+    );
+}
+
+#[test]
+fn test_centroid() {
+    let mut corners = Vec::new();
+    for i in 0..8 {
+        let bit = |b: usize| if i & (1 << b) == 0 { -1.0 } else { 3.0 };
+        corners.push(vec3(bit(0), bit(1), bit(2)));
+    }
+    assert_ulps_eq!(centroid(&corners).unwrap(), vec3(1.0, 1.0, 1.0));
+
+    let point = vec3(1.5f64, -2.5, 3.0);
+    assert_eq!(centroid(&[point]), Some(point));
+    assert_eq!(centroid::<Vector3<f64>>(&[]), None);
+
+    // Summing these first would overflow to infinity.
+    let huge = [vec2(f64::MAX, -f64::MAX), vec2(f64::MAX, -f64::MAX)];
+    assert_eq!(centroid(&huge), Some(vec2(f64::MAX, -f64::MAX)));
+}
+
+#[test]
+fn test_ord() {
+    assert!(vec3(1, 2, 3) < vec3(1, 3, 0));
+    assert!(vec3(2, 0, 0) > vec3(1, 9, 9));
+    assert_eq!(vec2(-1, 5).cmp(&vec2(-1, 5)), Ordering::Equal);
+
+    let mut vertices = vec![
+        vec3(1, 0, 0),
+        vec3(0, 1, 0),
+        vec3(1, 0, 0),
+        vec3(0, 0, 1),
+        vec3(0, 1, 0),
+    ];
+    vertices.sort();
+    vertices.dedup();
+    assert_eq!(vertices, [vec3(0, 0, 1), vec3(0, 1, 0), vec3(1, 0, 0)]);
+
+    let set: BTreeSet<_> = [vec4(1u8, 2, 3, 4), vec4(0, 9, 9, 9), vec4(1, 2, 3, 4)]
+        .iter()
+        .cloned()
+        .collect();
+    assert_eq!(
+        set.iter().cloned().collect::<Vec<_>>(),
+        [vec4(0, 9, 9, 9), vec4(1, 2, 3, 4)]
+    );
+}
+
+#[test]
+fn test_hash() {
+    fn hash<T: Hash>(value: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    assert_eq!(hash(&vec3(1, -2, 3)), hash(&vec3(1, -2, 3)));
+    assert_eq!(hash(&vec2(7u64, 8)), hash(&Vector2::from([7u64, 8])));
+
+    let set: HashSet<_> = [vec3(1, 0, 0), vec3(0, 1, 0), vec3(1, 0, 0)]
+        .iter()
+        .cloned()
+        .collect();
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(&vec3(0, 1, 0)));
+}
+
+#[test]
+fn test_cmp_lex() {
+    assert_eq!(
+        vec3(1.0, 2.0, 3.0).cmp_lex(&vec3(1.0, 2.0, 4.0)),
+        Ordering::Less
+    );
+    assert_eq!(
+        vec3(1.0, 2.0, 3.0).cmp_lex(&vec3(0.5, 9.0, 9.0)),
+        Ordering::Greater
+    );
+    assert_eq!(vec2(0.0, 1.0).cmp_lex(&vec2(-0.0, 1.0)), Ordering::Equal);
+    assert_eq!(
+        vec2(f64::NAN, 1.0).cmp_lex(&vec2(f64::INFINITY, 1.0)),
+        Ordering::Greater
+    );
+    assert_eq!(
+        vec2(1.0, f64::NAN).cmp_lex(&vec2(1.0, f64::NAN)),
+        Ordering::Equal
+    );
+    assert_eq!(
+        vec3(1.0, 2.0, 3.0).partial_cmp(&vec3(1.0, 2.0, 4.0)),
+        Some(Ordering::Less)
+    );
+
+    // Sorting is stable, so vectors that compare equal keep their order.
+    let mut tagged = [
+        (vec2(1.0, 0.0), 0),
+        (vec2(0.0, f64::NAN), 1),
+        (vec2(1.0, 0.0), 2),
+        (vec2(-0.0, f64::NAN), 3),
+        (vec2(0.0, 5.0), 4),
+        (vec2(-0.0, 0.0), 5),
+        (vec2(0.0, 0.0), 6),
+    ];
+    tagged.sort_by(|a, b| a.0.cmp_lex(&b.0));
+    let order: Vec<_> = tagged.iter().map(|&(_, i)| i).collect();
+    assert_eq!(order, [5, 6, 4, 1, 3, 0, 2]);
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(vec3(1, -2, 3).to_string(), "(1, -2, 3)");
+    assert_eq!(vec2(0.5f64, -1.25).to_string(), "(0.5, -1.25)");
+    assert_eq!(
+        format!("{:.2}", vec4(1.0f32, 2.0, -3.0, 0.125)),
+        "(1.00, 2.00, -3.00, 0.12)"
+    );
+    assert_eq!(vec1(7u8).to_string(), "(7)");
+}
+
+#[test]
+fn test_from_str() {
+    assert_eq!("(1, -2, 3)".parse(), Ok(vec3(1, -2, 3)));
+    assert_eq!(
+        "  ( 1.5 ,-2.5e-3,  3E2 ) ".parse(),
+        Ok(vec3(1.5, -2.5e-3, 300.0))
+    );
+    assert_eq!("(1,2)".parse(), Ok(vec2(1u32, 2)));
+    assert_eq!(
+        "(-0.25, 1e10, 4, -1e-10)".parse(),
+        Ok(vec4(-0.25f64, 1e10, 4.0, -1e-10))
+    );
+
+    assert_eq!("(1, 2)".parse::<Vector3<i32>>(), Err(ParseVectorError));
+    assert_eq!(
+        "(1, 2, 3, 4)".parse::<Vector3<i32>>(),
+        Err(ParseVectorError)
+    );
+    assert_eq!("(1, 2, 3,)".parse::<Vector3<i32>>(), Err(ParseVectorError));
+    assert_eq!("(1, x, 3)".parse::<Vector3<i32>>(), Err(ParseVectorError));
+    assert_eq!("1, 2, 3".parse::<Vector3<i32>>(), Err(ParseVectorError));
+    assert_eq!("(1, 2, 3".parse::<Vector3<i32>>(), Err(ParseVectorError));
+    assert_eq!("(-1, 2)".parse::<Vector2<u8>>(), Err(ParseVectorError));
+    assert_eq!("()".parse::<Vector1<f64>>(), Err(ParseVectorError));
+}
+
+#[test]
+fn test_display_from_str_round_trip() {
+    let vectors = [
+        vec3(1.0f64, -2.0, 3.0),
+        vec3(-0.1, 1e-20, -6.02214076e23),
+        vec3(f64::MAX, f64::MIN_POSITIVE, -0.0),
+    ];
+    for &v in &vectors {
+        assert_eq!(v.to_string().parse(), Ok(v));
+    }
+
+    let v = vec4(i64::MIN, -1, 0, i64::MAX);
+    assert_eq!(v.to_string().parse(), Ok(v));
+}
+
+#[test]
+fn test_tuple_array_slice_round_trip() {
+    let v = vec3(1, -2, 3);
+    let tuple: (i32, i32, i32) = v.into();
+    let array: [i32; 3] = v.into();
+    assert_eq!(tuple, (1, -2, 3));
+    assert_eq!(array, [1, -2, 3]);
+    assert_eq!(Vector3::from(tuple), v);
+    assert_eq!(Vector3::from(array), v);
+    assert_eq!(Vector3::from_slice(&array), Some(v));
+    assert_eq!(Vector3::from_slice(&[0, 1, -2, 3, 0][1..4]), Some(v));
+
+    assert_eq!(Vector1::from_slice(&[5.0f64]), Some(vec1(5.0)));
+    assert_eq!(Vector2::from_slice(&[1u8, 2]), Some(vec2(1, 2)));
+    assert_eq!(
+        Vector4::from_slice(&[1.0f32, 2.0, 3.0, 4.0]),
+        Some(vec4(1.0, 2.0, 3.0, 4.0))
+    );
+    let v4 = vec4(1.5f64, 2.5, 3.5, 4.5);
+    assert_eq!(Vector4::from(<(f64, f64, f64, f64)>::from(v4)), v4);
+    assert_eq!(Vector4::from(<[f64; 4]>::from(v4)), v4);
+
+    assert_eq!(Vector3::from_slice(&array[..2]), None);
+    assert_eq!(Vector3::from_slice(&[1, 2, 3, 4]), None);
+    assert_eq!(Vector2::<f64>::from_slice(&[]), None);
+    assert_eq!(Vector4::from_slice(&[1, 2, 3]), None);
+}
+
+#[test]
+fn test_relational_element_wise() {
+    let a = vec4(1.0, 2.0, 3.0, f64::NAN);
+    let b = vec4(2.0, 2.0, 1.0, 0.0);
+    assert_eq!(a.lt_element_wise(b), vec4(true, false, false, false));
+    assert_eq!(a.le_element_wise(b), vec4(true, true, false, false));
+    assert_eq!(a.gt_element_wise(b), vec4(false, false, true, false));
+    assert_eq!(a.ge_element_wise(b), vec4(false, true, true, false));
+    assert_eq!(a.eq_element_wise(b), vec4(false, true, false, false));
+    assert_eq!(a.ne_element_wise(b), vec4(true, false, true, true));
+    assert_eq!(a.ne_element_wise(a), vec4(false, false, false, true));
+
+    assert_eq!(
+        vec3(1.0, 2.0, 3.0).abs_diff_eq_element_wise(vec3(1.05, 2.5, 2.99), 0.1),
+        vec3(true, false, true)
+    );
+    assert_eq!(vec2(1, 5).lt_element_wise(vec2(3, 5)), vec2(true, false));
+
+    assert!(any(vec3(false, true, false)));
+    assert!(!any(vec3(false, false, false)));
+    assert!(all(vec2(true, true)));
+    assert!(!all(vec4(true, true, false, true)));
+    assert!(all(vec1(true)));
+}
+
+#[test]
+fn test_relational_glsl() {
+    // bool insideBox(vec3 p, vec3 center, vec3 halfExtents) {
+    //     return all(lessThanEqual(abs(p - center), halfExtents));
+    // }
+    let inside_box = |p: Vector3<f64>, center: Vector3<f64>, half_extents: Vector3<f64>| {
+        all((p - center).abs().le_element_wise(half_extents))
+    };
+    let center = vec3(1.0, 2.0, 3.0);
+    let half_extents = vec3(1.0, 0.5, 2.0);
+    assert!(inside_box(vec3(1.5, 2.5, 1.0), center, half_extents));
+    assert!(inside_box(center, center, half_extents));
+    assert!(!inside_box(vec3(1.5, 2.6, 1.0), center, half_extents));
+    assert!(!inside_box(vec3(f64::NAN, 2.0, 3.0), center, half_extents));
+
+    // bool anyNegative(vec4 v) { return any(lessThan(v, vec4(0.0))); }
+    let any_negative = |v: Vector4<f64>| any(v.lt_element_wise(Vector4::zero()));
+    assert!(any_negative(vec4(1.0, 2.0, -0.5, 4.0)));
+    assert!(!any_negative(vec4(1.0, 2.0, -0.0, 4.0)));
+
+    // bool changed = any(notEqual(previous, current));
+    let previous = vec2(3, 4);
+    assert!(!any(previous.ne_element_wise(vec2(3, 4))));
+    assert!(any(previous.ne_element_wise(vec2(3, 5))));
+}
+
+#[test]
+fn test_min_max_component() {
+    assert_eq!(vec3(1.0f64, -2.0, 3.0).min_component(), -2.0);
+    assert_eq!(vec3(1.0f64, -2.0, 3.0).max_component(), 3.0);
+    assert_eq!(vec2(5, 4).min_component(), 4);
+    assert_eq!(vec4(1u8, 9, 3, 7).max_component(), 9);
+    assert_eq!(vec1(4).min_component(), 4);
+
+    assert_eq!(vec3(1.0f64, -2.0, 3.0).max_component_index(), 2);
+    assert_eq!(vec3(1.0f64, -2.0, 3.0).abs().max_component_index(), 2);
+    assert_eq!(vec3(-5.0f64, -2.0, 3.0).abs().max_component_index(), 0);
+    assert_eq!(vec4(0, 8, 1, 2).max_component_index(), 1);
+    assert_eq!(vec2(1, 2).max_component_index(), 1);
+
+    // Ties resolve to the lowest index
+    assert_eq!(vec3(1.0f64, 1.0, 1.0).max_component_index(), 0);
+    assert_eq!(vec4(0, 2, 1, 2).max_component_index(), 1);
+    assert_eq!(vec3(0.0f64, 3.0, 3.0).max_component_index(), 1);
+}
+
 #[test]
 fn test_iter_sum() {
     impl_test_iter_sum!(
@@ -206,6 +569,68 @@ fn test_cross() {
     assert_eq!(a.cross(b), r);
 }
 
+#[test]
+fn test_unit_vectors() {
+    let (x, y, z) = (Vector3::unit_x(), Vector3::unit_y(), Vector3::unit_z());
+    assert_eq!(x, Vector3::new(1isize, 0, 0));
+    assert_eq!(x.cross(y), z);
+    assert_eq!(y.cross(z), x);
+    assert_eq!(z.cross(x), y);
+    assert_eq!(y.cross(x), -z);
+    assert_eq!(x + y + z, Vector3::from_value(1));
+
+    assert_eq!(
+        Vector2::unit_x() + Vector2::unit_y(),
+        Vector2::from_value(1u8)
+    );
+    assert_eq!(
+        Vector4::unit_x() + Vector4::unit_y() + Vector4::unit_z() + Vector4::unit_w(),
+        Vector4::from_value(1.0f32)
+    );
+    assert_eq!(Vector4::<f64>::unit_w().truncate(), Vector3::zero());
+    assert!(Vector2::<f64>::zero().is_zero());
+}
+
+fn check_orthonormal_basis(n: Vector3<f64>) {
+    let p = n.any_perpendicular();
+    assert_abs_diff_eq!(p.dot(n), 0.0, epsilon = 1e-12);
+    assert!(p.magnitude() >= n.magnitude() / 2.0f64.sqrt() * (1.0 - 1e-12));
+
+    let (t, b) = n.orthonormal_basis();
+    assert_abs_diff_eq!(t.magnitude(), 1.0, epsilon = 1e-12);
+    assert_abs_diff_eq!(b.magnitude(), 1.0, epsilon = 1e-12);
+    assert_abs_diff_eq!(t.dot(n), 0.0, epsilon = 1e-12);
+    assert_abs_diff_eq!(b.dot(n), 0.0, epsilon = 1e-12);
+    assert_abs_diff_eq!(t.dot(b), 0.0, epsilon = 1e-12);
+    assert_abs_diff_eq!(t.cross(b), n.normalize(), epsilon = 1e-12);
+    assert_abs_diff_eq!(
+        Matrix3::from_cols(t, b, n.normalize()).determinant(),
+        1.0,
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_orthonormal_basis() {
+    check_orthonormal_basis(vec3(1.0, -2.0, 2.0));
+    check_orthonormal_basis(vec3(-0.3, 0.9, 0.1));
+    check_orthonormal_basis(vec3(0.1, 1.0, 0.1));
+    check_orthonormal_basis(vec3(5.0, 0.0, -7.0));
+
+    for &axis in &[Vector3::unit_x(), Vector3::unit_y(), Vector3::unit_z()] {
+        for &sign in &[1.0, -1.0] {
+            check_orthonormal_basis(axis * sign);
+            check_orthonormal_basis(axis * sign + vec3(1e-9, -1e-9, 1e-9));
+            check_orthonormal_basis(axis * sign + vec3(-1e-17, 1e-17, 1e-17));
+        }
+    }
+    check_orthonormal_basis(vec3(1.0, 1.0, 1.0));
+    check_orthonormal_basis(vec3(1.0, 0.0, 1.0));
+    check_orthonormal_basis(vec3(1e-100, 0.0, -1e-100));
+
+    assert_eq!(Vector3::<f64>::zero().any_perpendicular(), Vector3::zero());
+}
+
 #[test]
 fn test_is_perpendicular() {
     assert!(Vector2::new(1.0f64, 0.0f64).is_perpendicular(Vector2::new(0.0f64, 1.0f64)));
@@ -257,6 +682,132 @@ mod test_magnitude {
     }
 }
 
+#[test]
+fn test_angle_vector2_signed() {
+    let x = Vector2::new(2.0f64, 0.0);
+    assert_ulps_eq!(x.angle(Vector2::new(1.0, 1.0)), Rad(f64::consts::FRAC_PI_4));
+    assert_ulps_eq!(
+        x.angle(Vector2::new(-1.0, 1.0)),
+        Rad(3.0 * f64::consts::FRAC_PI_4)
+    );
+    assert_ulps_eq!(
+        x.angle(Vector2::new(-1.0, -1.0)),
+        Rad(-3.0 * f64::consts::FRAC_PI_4)
+    );
+    assert_ulps_eq!(
+        x.angle(Vector2::new(1.0, -1.0)),
+        Rad(-f64::consts::FRAC_PI_4)
+    );
+    assert_ulps_eq!(
+        Vector2::new(1.0, 1.0).angle(x),
+        Rad(-f64::consts::FRAC_PI_4)
+    );
+
+    // Opposite vectors give a positive half turn
+    assert_eq!(x.angle(-x), Rad(f64::consts::PI));
+    let y = Vector2::new(0.0f64, 1.0);
+    assert_eq!(y.angle(-y), Rad(f64::consts::PI));
+    assert_eq!(y.angle(Vector2::new(0.0, -1.0)), Rad(f64::consts::PI));
+
+    // Zero length vectors give zero
+    assert_eq!(Vector2::zero().angle(Vector2::new(-1.0f64, -1.0)), Rad(0.0));
+    assert_eq!(x.angle(Vector2::zero()), Rad(0.0));
+}
+
+#[test]
+fn test_angle_vector3_precision() {
+    let a = Vector3::new(1.0f64, 0.0, 0.0);
+
+    // `acos` of the normalized dot product would round these to zero or a half turn
+    let b = Vector3::new(1.0f64, 1.0e-8, 0.0);
+    assert_relative_eq!(a.angle(b), Rad(1.0e-8), max_relative = 1.0e-6);
+    assert_relative_eq!(b.angle(a), Rad(1.0e-8), max_relative = 1.0e-6);
+
+    let c = Vector3::new(-1.0f64, 0.0, 1.0e-8);
+    assert_relative_eq!(
+        a.angle(c),
+        Rad(f64::consts::PI - 1.0e-8),
+        max_relative = 1.0e-12
+    );
+    assert!(a.angle(c) < Rad(f64::consts::PI));
+
+    assert_eq!(a.angle(a * 3.0), Rad(0.0));
+    assert_eq!(a.angle(-a), Rad(f64::consts::PI));
+}
+
+#[test]
+fn test_polar() {
+    assert_ulps_eq!(
+        Vector2::from_polar(2.0f64, Deg(90.0)),
+        Vector2::new(0.0, 2.0)
+    );
+    assert_ulps_eq!(
+        Vector2::from_polar(1.0f64, Rad::turn_div_2()),
+        Vector2::new(-1.0, 0.0)
+    );
+
+    assert_eq!(Vector2::new(3.0f64, 0.0).to_polar(), (3.0, Rad(0.0)));
+    assert_eq!(Vector2::new(3.0f64, -0.0).to_polar(), (3.0, Rad(0.0)));
+    assert_eq!(Vector2::<f64>::zero().to_polar(), (0.0, Rad(0.0)));
+
+    let (r, theta) = Vector2::new(0.0f64, -2.0).to_polar();
+    assert_ulps_eq!(r, 2.0);
+    assert_ulps_eq!(Deg::from(theta), Deg(270.0));
+}
+
+#[test]
+fn test_polar_round_trip() {
+    for &theta in &[0.0f64, 45.0, 135.0, 225.0, 315.0, 359.9] {
+        let v = Vector2::from_polar(1.5f64, Deg(theta));
+        let (r, t) = v.to_polar();
+        assert_relative_eq!(r, 1.5, epsilon = 1.0e-12);
+        assert_relative_eq!(Deg::from(t), Deg(theta), epsilon = 1.0e-9);
+        assert_ulps_eq!(Vector2::from_polar(r, t), v);
+    }
+}
+
+#[test]
+fn test_sphere() {
+    let v = Vector3::from_sphere(2.0f64, Deg(90.0), Deg(90.0));
+    assert_ulps_eq!(v, Vector3::new(0.0, 2.0, 0.0));
+    let v = Vector3::from_sphere(1.0f64, Rad::turn_div_2(), Rad(0.0));
+    assert_ulps_eq!(v, Vector3::new(0.0, 0.0, -1.0));
+
+    let (r, theta, phi) = Vector3::new(0.0f64, 0.0, 3.0).to_sphere();
+    assert_eq!((r, theta, phi), (3.0, Rad(0.0), Rad(0.0)));
+    let (r, theta, phi) = Vector3::new(0.0f64, 0.0, -3.0).to_sphere();
+    assert_eq!((r, theta, phi), (3.0, Rad(f64::consts::PI), Rad(0.0)));
+    let (r, theta, phi) = Vector3::new(-1.0f64, -0.0, 0.0).to_sphere();
+    assert_eq!(
+        (r, theta, phi),
+        (1.0, Rad(f64::consts::FRAC_PI_2), Rad(f64::consts::PI))
+    );
+    assert_eq!(
+        Vector3::<f64>::zero().to_sphere(),
+        (0.0, Rad(0.0), Rad(0.0))
+    );
+}
+
+#[test]
+fn test_sphere_round_trip() {
+    for &theta in &[0.1f64, 45.0, 90.0, 135.0, 179.9] {
+        for &phi in &[-179.9f64, -90.0, 0.0, 30.0, 90.0, 180.0] {
+            let v = Vector3::from_sphere(2.5f64, Deg(theta), Deg(phi));
+            let (r, t, p) = v.to_sphere();
+            assert_relative_eq!(r, 2.5, epsilon = 1.0e-12);
+            assert_relative_eq!(Deg::from(t), Deg(theta), epsilon = 1.0e-9);
+            assert_relative_eq!(Deg::from(p), Deg(phi), epsilon = 1.0e-9);
+        }
+    }
+
+    // Points on the equator
+    let (x, y) = (Vector3::<f64>::unit_x(), Vector3::<f64>::unit_y());
+    for &v in &[x, y, -x, -y] {
+        let (r, theta, phi) = v.to_sphere();
+        assert_ulps_eq!(Vector3::from_sphere(r, theta, phi), v);
+    }
+}
+
 #[test]
 fn test_angle() {
     assert_ulps_eq!(
@@ -304,7 +855,6 @@ fn test_angle() {
 
 #[test]
 fn test_normalize() {
-    // TODO: test normalize_to, normalize_sel.0, and normalize_self_to
     assert_ulps_eq!(
         Vector2::new(3.0f64, 4.0f64).normalize(),
         &Vector2::new(3.0 / 5.0, 4.0 / 5.0)
@@ -347,6 +897,312 @@ fn test_checked_normalize() {
     );
 }
 
+macro_rules! impl_test_inner_space {
+    ($VectorN:ident { $($field:ident),+ }, $a:expr, $b:expr) => ({
+        let (a, b) = ($a, $b);
+        let expected = 0.0 $(+ a.$field * b.$field)+;
+        assert_eq!(a.dot(b), expected);
+        assert_eq!(dot(a, b), expected);
+        assert_eq!(a.magnitude2(), a.dot(a));
+        assert_ulps_eq!(a.magnitude(), a.dot(a).sqrt());
+
+        assert_ulps_eq!(a.normalize().magnitude(), 1.0);
+        assert_ulps_eq!(a.normalize() * a.magnitude(), a);
+        assert_ulps_eq!(a.normalize_to(3.0).magnitude(), 3.0);
+        assert_ulps_eq!(a.normalize_to(3.0), a.normalize() * 3.0);
+
+        let mut c = a;
+        c.normalize_self();
+        assert_eq!(c, a.normalize());
+        c.normalize_self_to(3.0);
+        assert_eq!(c, a.normalize().normalize_to(3.0));
+        let mut c = a;
+        c.normalize_self_to(3.0);
+        assert_eq!(c, a.normalize_to(3.0));
+        let mut zero = $VectorN::<f64>::zero();
+        zero.normalize_self();
+        assert!(!zero.is_finite());
+
+        assert_eq!(a.lerp(b, 0.0), a);
+        assert_eq!(a.lerp(b, 1.0), b);
+        assert_ulps_eq!(a.lerp(b, 0.5), $VectorN::new($((a.$field + b.$field) / 2.0),+));
+
+        assert_ulps_eq!(a.distance(b), (a - b).magnitude());
+        assert_ulps_eq!(a.distance2(b), (a - b).magnitude2());
+        assert_eq!(a.distance(a), 0.0);
+    })
+}
+
+#[test]
+fn test_inner_space() {
+    impl_test_inner_space!(Vector2 { x, y }, vec2(3.0f64, -4.0), vec2(0.5f64, 2.0));
+    impl_test_inner_space!(
+        Vector3 { x, y, z },
+        vec3(2.0f64, -3.0, 6.0),
+        vec3(0.5f64, 2.0, -1.0)
+    );
+    impl_test_inner_space!(
+        Vector4 { x, y, z, w },
+        vec4(1.0f64, 2.0, -4.0, 10.0),
+        vec4(0.5f64, 2.0, -1.0, 3.0)
+    );
+}
+
+#[test]
+fn test_distance() {
+    let (a, b) = (vec2(1.0f64, 2.0), vec2(4.0f64, 6.0));
+    assert_eq!(a.distance(b), 5.0);
+    assert_eq!(a.distance2(b), 25.0);
+
+    let (a, b) = (vec3(1.0f64, -2.0, 3.0), vec3(3.0f64, 1.0, -3.0));
+    assert_eq!(a.distance(b), 7.0);
+    assert_eq!(a.distance2(b), 49.0);
+
+    let (a, b) = (vec4(1.0f64, 2.0, 3.0, 4.0), vec4(2.0f64, 3.0, 4.0, 5.0));
+    assert_eq!(a.distance(b), 2.0);
+    assert_eq!(a.distance2(b), 4.0);
+
+    // distance2 does not require a floating point scalar
+    assert_eq!(vec3(1, 2, 3).distance2(vec3(3, 2, 1)), 8);
+
+    // Symmetric, and zero only for equal vectors
+    let (a, b) = (vec3(0.5f64, -1.5, 2.0), vec3(-3.0f64, 0.25, 1.0));
+    assert_eq!(a.distance(b), b.distance(a));
+    assert_eq!(a.distance2(b), b.distance2(a));
+    assert_ulps_eq!(a.distance2(b), a.distance(b) * a.distance(b));
+    assert_eq!(a.distance(a), 0.0);
+
+    assert!(vec2(f64::NAN, 0.0).distance(Vector2::zero()).is_nan());
+    assert_eq!(
+        vec2(f64::INFINITY, 0.0).distance(Vector2::zero()),
+        f64::INFINITY
+    );
+}
+
+#[test]
+fn test_map_zip_fold() {
+    let v = vec3(1.0f64, -2.0, 3.0);
+    assert_eq!(v.map(|x| x * 2.0), v * 2.0);
+    assert_eq!(v.map(|x| x < 0.0), vec3(false, true, false));
+    assert_eq!(
+        v.zip(vec3(4.0, 5.0, 6.0), |a, b| a * b),
+        vec3(4.0, -10.0, 18.0)
+    );
+
+    assert_eq!(v.fold(0.0, |acc, x| acc + x), v.sum());
+    assert_eq!(v.fold(1.0, |acc, x| acc * x), v.product());
+    assert_eq!(vec4(1, 2, 3, 4).fold(0, |acc, x| acc * 10 + x), 1234);
+    assert_eq!(vec2(3u8, 200).fold(0u32, |acc, x| acc + x as u32), 203);
+    assert_eq!(vec2(-1.0f64, 0.5).fold(f64::NEG_INFINITY, f64::max), 0.5);
+}
+
+#[test]
+fn test_min_max_element_wise() {
+    let a = vec3(1.0f64, -2.0, 3.0);
+    let b = vec3(0.5f64, 4.0, 3.0);
+    assert_eq!(a.min_element_wise(b), vec3(0.5, -2.0, 3.0));
+    assert_eq!(a.max_element_wise(b), vec3(1.0, 4.0, 3.0));
+    assert_eq!(vec2(1, 5).min_element_wise(vec2(3, -4)), vec2(1, -4));
+    assert_eq!(
+        vec4(1u32, 5, 0, 7).max_element_wise(vec4(3, 4, 0, 8)),
+        vec4(3, 5, 0, 8)
+    );
+}
+
+#[test]
+fn test_clamp() {
+    let v = vec3(-1.5f64, 0.25, 7.0);
+    assert_eq!(v.clamp(0.0, 1.0), vec3(0.0, 0.25, 1.0));
+    assert_eq!(
+        v.clamp_element_wise(vec3(-1.0, 0.5, 0.0), vec3(0.0, 1.0, 10.0)),
+        vec3(-1.0, 0.5, 7.0)
+    );
+    assert_eq!(vec2(-5, 5).clamp(-2, 2), vec2(-2, 2));
+    assert_eq!(
+        vec4(1.0f32, 2.0, 3.0, 4.0).clamp(2.0, 2.0),
+        vec4(2.0, 2.0, 2.0, 2.0)
+    );
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic]
+fn test_clamp_min_greater_than_max() {
+    vec3(1.0f64, 2.0, 3.0).clamp_element_wise(vec3(0.0, 3.0, 0.0), vec3(1.0, 2.0, 1.0));
+}
+
+#[test]
+fn test_abs_sign() {
+    let v = vec4(-1.5f64, 0.0, 2.0, -0.0);
+    assert_eq!(v.abs(), vec4(1.5, 0.0, 2.0, 0.0));
+    assert_eq!(v.sign(), vec4(-1.0, 0.0, 1.0, 0.0));
+    assert_eq!(vec3(-3, 0, 4).abs(), vec3(3, 0, 4));
+    assert_eq!(vec3(-3, 0, 4).sign(), vec3(-1, 0, 1));
+    assert_eq!(vec2(-0.5f32, 8.0).sign(), vec2(-1.0, 1.0));
+    assert_eq!(v.sign().zip(v.abs(), |s, a| s * a), v);
+}
+
+#[test]
+fn test_lerp_extrapolates() {
+    let a = vec3(1.0f64, 2.0, 3.0);
+    let b = vec3(3.0f64, 2.0, -1.0);
+    assert_eq!(a.lerp(b, 2.0), vec3(5.0, 2.0, -5.0));
+    assert_eq!(a.lerp(b, -1.0), vec3(-1.0, 2.0, 7.0));
+}
+
+#[test]
+fn test_mix() {
+    let a = vec3(1.0f64, 2.0, 3.0);
+    let b = vec3(3.0f64, 2.0, -1.0);
+    assert_eq!(a.mix(b, vec3(0.0, 0.5, 1.0)), vec3(1.0, 2.0, -1.0));
+    assert_eq!(a.mix(b, vec3(0.5, 0.5, 0.5)), a.lerp(b, 0.5));
+    assert_eq!(a.mix(b, vec3(2.0, -1.0, 0.25)), vec3(5.0, 2.0, 2.0));
+    assert_eq!(
+        vec2(0.0f32, 10.0).mix(vec2(10.0, 0.0), vec2(0.1, 0.1)),
+        vec2(1.0, 9.0)
+    );
+}
+
+#[test]
+fn test_smoothstep() {
+    let edge0 = vec4(0.0f64, 0.0, 0.0, 1.0);
+    let edge1 = vec4(1.0f64, 1.0, 1.0, 3.0);
+
+    // Clamped outside of the edges, unlike lerp
+    assert_eq!(
+        vec4(-1.0f64, 2.0, 0.5, 2.0).smoothstep(edge0, edge1),
+        vec4(0.0, 1.0, 0.5, 0.5)
+    );
+    assert_eq!(
+        vec4(0.25f64, 0.75, 0.0, 3.0).smoothstep(edge0, edge1),
+        vec4(0.15625, 0.84375, 0.0, 1.0)
+    );
+
+    // Reversed edges invert the curve, as in GLSL
+    assert_eq!(
+        vec2(0.25f64, 2.0).smoothstep(vec2(1.0, 1.0), vec2(0.0, 0.0)),
+        vec2(0.84375, 0.0)
+    );
+}
+
+#[test]
+fn test_extend_truncate() {
+    let v2 = vec2(1.0f64, 2.0);
+    let v3 = vec3(1.0f64, 2.0, 3.0);
+    let v4 = vec4(1.0f64, 2.0, 3.0, 4.0);
+    assert_eq!(v2.extend(3.0), v3);
+    assert_eq!(v3.extend(4.0), v4);
+    assert_eq!(v3.truncate(), v2);
+    assert_eq!(v4.truncate(), v3);
+    assert_eq!(v2.extend(7.0).truncate(), v2);
+    assert_eq!(v3.extend(7.0).truncate(), v3);
+    assert_eq!(v4.truncate().extend(4.0), v4);
+}
+
+#[test]
+fn test_homogenize() {
+    assert_eq!(
+        vec4(2.0f64, 4.0, 6.0, 2.0).homogenize(),
+        vec3(1.0, 2.0, 3.0)
+    );
+    assert_eq!(
+        vec4(1.0f64, 2.0, 3.0, 1.0).homogenize(),
+        vec3(1.0, 2.0, 3.0)
+    );
+    assert_eq!(vec4(-3, 6, 9, 3).homogenize(), vec3(-1, 2, 3));
+    assert_eq!(
+        vec4(2.0f64, 4.0, 6.0, 2.0).homogenize(),
+        Point3::from_homogeneous(vec4(2.0, 4.0, 6.0, 2.0)).to_vec()
+    );
+
+    let direction = vec4(1.0f64, 0.0, -1.0, 0.0).homogenize();
+    assert_eq!(direction.x, f64::INFINITY);
+    assert!(direction.y.is_nan());
+    assert_eq!(direction.z, f64::NEG_INFINITY);
+}
+
+#[test]
+fn test_perp_dot() {
+    let a = vec2(3.0f64, 1.0);
+    let b = vec2(-1.0f64, 2.0);
+    assert_eq!(a.perp_dot(b), a.extend(0.0).cross(b.extend(0.0)).z);
+    assert_eq!(a.perp_dot(b), -b.perp_dot(a));
+    assert_eq!(a.perp_dot(a * 2.0), 0.0);
+    assert_eq!(Vector2::<f64>::unit_x().perp_dot(Vector2::unit_y()), 1.0);
+}
+
+#[test]
+fn test_try_normalize() {
+    assert_ulps_eq!(
+        Vector2::new(3.0f64, 4.0).try_normalize().unwrap(),
+        Vector2::new(0.6, 0.8)
+    );
+    assert_ulps_eq!(
+        Vector3::new(2.0f64, 3.0, 6.0).try_normalize().unwrap(),
+        Vector3::new(2.0f64, 3.0, 6.0).normalize()
+    );
+    assert_ulps_eq!(
+        Vector4::new(1.0e-100f64, 0.0, 0.0, 0.0)
+            .try_normalize()
+            .unwrap(),
+        Vector4::unit_x()
+    );
+
+    assert_eq!(Vector2::<f64>::zero().try_normalize(), None);
+    assert_eq!(Vector3::<f64>::zero().try_normalize(), None);
+    assert_eq!(Vector4::<f32>::zero().try_normalize(), None);
+    // The squared magnitude underflows, or is subnormal
+    assert_eq!(Vector3::new(1.0e-200f64, 0.0, 0.0).try_normalize(), None);
+    assert_eq!(Vector3::new(1.0e-155f64, 0.0, 0.0).try_normalize(), None);
+    assert_eq!(Vector3::new(f64::NAN, 0.0, 0.0).try_normalize(), None);
+    assert_eq!(Vector3::new(f64::INFINITY, 0.0, 0.0).try_normalize(), None);
+    assert_eq!(Vector2::new(1.0e200f64, 1.0e200).try_normalize(), None);
+}
+
+#[test]
+fn test_normalize_or() {
+    let fallback = Vector3::unit_z();
+    assert_eq!(
+        Vector3::new(0.0f64, 5.0, 0.0).normalize_or(fallback),
+        Vector3::unit_y()
+    );
+    assert_eq!(Vector3::zero().normalize_or(fallback), fallback);
+    assert_eq!(
+        Vector2::new(1.0e-300f64, 0.0).normalize_or(Vector2::unit_y()),
+        Vector2::unit_y()
+    );
+}
+
+#[test]
+fn test_predicates() {
+    let nan = Vector3::new(1.0f64, f64::NAN, 0.0);
+    assert!(!nan.is_finite());
+    assert!(!nan.is_zero());
+    assert!(!nan.is_normalized());
+    assert!(!nan.is_perpendicular(Vector3::unit_x()));
+    assert!(!ulps_eq!(nan, nan));
+    assert!(!Vector2::new(f64::INFINITY, 0.0).is_finite());
+    assert!(Vector4::new(1.0f64, -2.0, 3.0, 4.0).is_finite());
+
+    // `is_zero` is exact, fuzzy comparisons use the approx macros
+    let tiny = Vector3::new(1.0e-20f64, 0.0, 0.0);
+    assert!(Vector3::<f64>::zero().is_zero());
+    assert!(!tiny.is_zero());
+    assert!(abs_diff_eq!(tiny, Vector3::zero()));
+
+    assert!(Vector2::new(0.6f64, 0.8).is_normalized());
+    assert!(Vector3::new(2.0f64, 3.0, 6.0).normalize().is_normalized());
+    assert!(Vector4::<f64>::unit_w().is_normalized());
+    assert!(!Vector3::new(1.0f64 + 1.0e-7, 0.0, 0.0).is_normalized());
+    assert!(!Vector2::new(0.6f64, 0.8 - 1.0e-7).is_normalized());
+    assert!(!Vector3::<f64>::zero().is_normalized());
+
+    assert!(Vector3::new(1.0f64, 1.0, 0.0).is_perpendicular(Vector3::new(-1.0, 1.0, 5.0)));
+    assert!(
+        !Vector3::new(1.0f64, 1.0, 0.0).is_perpendicular(Vector3::new(-1.0, 1.0 + 1.0e-7, 5.0))
+    );
+}
+
 #[test]
 fn test_project_on() {
     assert_ulps_eq!(
@@ -363,6 +1219,103 @@ fn test_project_on() {
     );
 }
 
+#[test]
+fn test_project_on_zero() {
+    assert_eq!(
+        Vector2::new(1.0f64, 2.0).project_on(Vector2::zero()),
+        Vector2::zero()
+    );
+    assert_eq!(
+        Vector3::new(1.0f64, 2.0, 3.0).project_on(Vector3::zero()),
+        Vector3::zero()
+    );
+    assert_eq!(
+        Vector3::new(1.0f64, 2.0, 3.0).reject_from(Vector3::zero()),
+        Vector3::new(1.0, 2.0, 3.0)
+    );
+}
+
+#[test]
+fn test_reject_from() {
+    assert_ulps_eq!(
+        Vector2::new(-1.0f64, 5.0).reject_from(Vector2::new(2.0, 4.0)),
+        &Vector2::new(-14.0 / 5.0, 7.0 / 5.0)
+    );
+    assert_ulps_eq!(
+        Vector3::new(5.0f64, 6.0, 7.0).reject_from(Vector3::new(1.0, 1.0, 1.0)),
+        &Vector3::new(-1.0, 0.0, 1.0)
+    );
+
+    // Parallel and perpendicular vectors
+    let v = Vector3::new(2.0f64, -4.0, 6.0);
+    assert_eq!(v.reject_from(v * -0.5), Vector3::zero());
+    assert_eq!(v.reject_from(Vector3::new(3.0, 0.0, -1.0)), v);
+
+    let target = Vector3::new(1.0f64, 2.0, -2.0);
+    let projection = v.project_on(target);
+    let rejection = v.reject_from(target);
+    assert_ulps_eq!(projection + rejection, v);
+    assert_relative_eq!(rejection.dot(target), 0.0, epsilon = 1.0e-12);
+    assert_relative_eq!(projection.cross(target).magnitude(), 0.0, epsilon = 1.0e-12);
+}
+
+#[test]
+fn test_reflect() {
+    let normal = Vector3::unit_y();
+    assert_eq!(
+        Vector3::new(1.0f64, -1.0, 0.0).reflect(normal),
+        Vector3::new(1.0, 1.0, 0.0)
+    );
+    assert_eq!(
+        Vector2::new(1.0f64, -2.0).reflect(Vector2::unit_y()),
+        Vector2::new(1.0, 2.0)
+    );
+
+    // Vectors in the plane are unchanged, and the normal is reversed
+    assert_eq!(
+        Vector3::new(3.0f64, 0.0, -2.0).reflect(normal),
+        Vector3::new(3.0, 0.0, -2.0)
+    );
+    assert_eq!(normal.reflect(normal), -normal);
+    assert_eq!(Vector3::<f64>::zero().reflect(normal), Vector3::zero());
+
+    let v = Vector3::new(1.0f64, -2.0, 4.0);
+    let normal = Vector3::new(1.0f64, 2.0, -2.0) / 3.0;
+    let reflected = v.reflect(normal);
+    assert_relative_eq!(reflected.magnitude(), v.magnitude(), epsilon = 1.0e-12);
+    assert_relative_eq!(reflected.dot(normal), -v.dot(normal), epsilon = 1.0e-12);
+    assert_relative_eq!(reflected.reflect(normal), v, epsilon = 1.0e-12);
+}
+
+#[test]
+fn test_refract() {
+    let normal = Vector3::unit_y();
+    let incident = Vector3::new(1.0f64, -1.0, 0.0).normalize();
+
+    // Refracting between equal refractive indices has no effect
+    assert_ulps_eq!(incident.refract(normal, 1.0).unwrap(), incident);
+
+    // Snell's law, from air into glass
+    let eta = 1.0 / 1.5;
+    let refracted = incident.refract(normal, eta).unwrap();
+    assert_relative_eq!(refracted.magnitude(), 1.0, epsilon = 1.0e-12);
+    assert!(refracted.y < 0.0);
+    let sin_incident = incident.cross(normal).magnitude();
+    let sin_refracted = refracted.cross(normal).magnitude();
+    assert_relative_eq!(sin_refracted, sin_incident * eta, epsilon = 1.0e-12);
+
+    // Head on incidence passes straight through
+    assert_ulps_eq!((-normal).refract(normal, eta).unwrap(), -normal);
+
+    // Total internal reflection at a grazing angle, from glass into air
+    let grazing = Vector3::new(1.0f64, -0.1, 0.0).normalize();
+    assert_eq!(grazing.refract(normal, 1.5), None);
+    assert!(Vector2::new(1.0f64, -0.1)
+        .normalize()
+        .refract(Vector2::unit_y(), 1.5)
+        .is_none());
+}
+
 #[test]
 fn test_cast() {
     assert_ulps_eq!(
@@ -378,3 +1331,66 @@ fn test_cast() {
         Vector4::new(13.5f32, -4.6, -8.3, 2.41)
     );
 }
+
+#[cfg(feature = "rand")]
+mod rand_unit {
+    use cgmath::*;
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_unit_length() {
+        let mut rng = SmallRng::seed_from_u64(0x5eed);
+        for _ in 0..100 {
+            let v = Vector3::<f64>::rand_unit(&mut rng);
+            assert_ulps_eq!(v.magnitude(), 1.0);
+        }
+    }
+
+    #[test]
+    fn test_orthonormal_basis() {
+        let mut rng = SmallRng::seed_from_u64(0x5eed);
+        for _ in 0..1000 {
+            let n = Vector3::<f64>::rand_unit(&mut rng);
+            let (t, b) = n.orthonormal_basis();
+            assert_abs_diff_eq!(t.dot(n), 0.0, epsilon = 1e-12);
+            assert_abs_diff_eq!(b.dot(n), 0.0, epsilon = 1e-12);
+            assert_abs_diff_eq!(t.dot(b), 0.0, epsilon = 1e-12);
+            assert_abs_diff_eq!(t.magnitude(), 1.0, epsilon = 1e-12);
+            assert_abs_diff_eq!(b.magnitude(), 1.0, epsilon = 1e-12);
+            assert_abs_diff_eq!(t.cross(b), n, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_uniform_on_sphere() {
+        let mut rng = SmallRng::seed_from_u64(0x5eed);
+        let samples = 10_000;
+
+        // On a uniformly sampled sphere, the projection onto any axis is
+        // uniformly distributed in `[-1, 1]`. Sampling the cube and
+        // normalizing would cluster the samples around the diagonals.
+        let axes = [
+            Vector3::unit_x(),
+            Vector3::unit_z(),
+            Vector3::new(1.0f64, 1.0, 1.0).normalize(),
+        ];
+        let mut bins = [[0usize; 4]; 3];
+        let mut sum = Vector3::zero();
+        for _ in 0..samples {
+            let v = Vector3::<f64>::rand_unit(&mut rng);
+            sum += v;
+            for (axis, bins) in axes.iter().zip(bins.iter_mut()) {
+                let bin = ((v.dot(*axis) + 1.0) * 2.0) as usize;
+                bins[bin.min(3)] += 1;
+            }
+        }
+
+        assert_relative_eq!(sum / samples as f64, Vector3::zero(), epsilon = 0.03);
+        for bins in bins.iter() {
+            for &count in bins.iter() {
+                assert_relative_eq!(count as f64 / samples as f64, 0.25, epsilon = 0.02);
+            }
+        }
+    }
+}